@@ -0,0 +1,207 @@
+//! ```ignore
+//! BAnyError: boxed `Error + Send + Sync` plus formatted context strings
+//! BAnyResult<T> = BResult<T, BAnyError>
+//!
+//! new(E) -> BAnyError
+//! from_boxed(Box<dyn Error + Send + Sync>) -> BAnyError
+//! from_message(impl Display) -> BAnyError
+//!
+//! as_source() -> &(dyn Error + Send + Sync)
+//! as_chain() -> ErrChain
+//! as_context() -> &[String] innermost first
+//! as_push_context(impl Display)
+//!
+//! is<E>() -> bool
+//! as_downcast_ref<E>() -> BOption<&E>
+//! as_downcast_mut<E>() -> BOption<&mut E>
+//! into_downcast<E>() -> BResult<E, BAnyError> context is dropped on success
+//!
+//! BResult<T, E> where E: Error + Send + Sync
+//! into_map_err_any() -> BAnyResult<T>
+//!
+//! BAnyResult<T>, reached from any error through into_map_err_any()
+//! into_context(impl Display) -> BAnyResult<T>
+//! into_with_context(|| impl Display) -> BAnyResult<T>
+//! BResult<T, BError<E, N>>::into_context only takes `&'static str`, see bettererror
+//!
+//! bbail!("format", args..) returns Err(BAnyError) from the enclosing function
+//! bensure!(condition, "format", args..) bbail!s when the condition is false
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterreport::ErrChain;
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+#[doc(hidden)]
+pub use alloc::format as __format;
+
+pub type BAnyResult<T> = BResult<T, BAnyError>;
+
+pub struct BAnyError {
+    source: Box<dyn Error + Send + Sync + 'static>,
+    context: Vec<String>,
+}
+
+struct MessageError {
+    message: String,
+}
+
+impl fmt::Debug for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.message, f)
+    }
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for MessageError {}
+
+impl BAnyError {
+    pub fn new<E: Error + Send + Sync + 'static>(error: E) -> Self {
+        BAnyError::from_boxed(Box::new(error))
+    }
+
+    pub fn from_boxed(source: Box<dyn Error + Send + Sync + 'static>) -> Self {
+        BAnyError {
+            source,
+            context: Vec::new(),
+        }
+    }
+
+    pub fn from_message(message: impl fmt::Display) -> Self {
+        BAnyError::new(MessageError {
+            message: message.to_string(),
+        })
+    }
+
+    pub fn as_source(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.source
+    }
+
+    pub fn as_chain(&self) -> ErrChain<'_> {
+        ErrChain::new(&*self.source)
+    }
+
+    pub fn as_context(&self) -> &[String] {
+        &self.context
+    }
+
+    pub fn as_push_context(&mut self, context: impl fmt::Display) {
+        self.context.push(context.to_string());
+    }
+
+    pub fn is<E: Error + 'static>(&self) -> bool {
+        self.source.is::<E>()
+    }
+
+    pub fn as_downcast_ref<E: Error + 'static>(&self) -> BOption<&E> {
+        match self.source.downcast_ref::<E>() {
+            Option::Some(e) => Some(e),
+            Option::None => None,
+        }
+    }
+
+    pub fn as_downcast_mut<E: Error + 'static>(&mut self) -> BOption<&mut E> {
+        match self.source.downcast_mut::<E>() {
+            Option::Some(e) => Some(e),
+            Option::None => None,
+        }
+    }
+
+    pub fn into_downcast<E: Error + 'static>(self) -> BResult<E, BAnyError> {
+        let BAnyError { source, context } = self;
+        match source.downcast::<E>() {
+            Result::Ok(e) => Ok(*e),
+            Result::Err(source) => Err(BAnyError { source, context }),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<E> for BAnyError {
+    fn from(error: E) -> Self {
+        BAnyError::new(error)
+    }
+}
+
+impl fmt::Display for BAnyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for context in self.context.iter().rev() {
+            write!(f, "{}: ", context)?;
+        }
+        write!(f, "{}", self.source)
+    }
+}
+
+impl fmt::Debug for BAnyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)?;
+        for (index, source) in self.as_chain().skip(1).enumerate() {
+            if index == 0 {
+                f.write_str("\n\ncaused by:")?;
+            }
+            write!(f, "\n    {}: {}", index + 1, source)?;
+        }
+        Result::Ok(())
+    }
+}
+
+impl<T, E: Error + Send + Sync + 'static> BResult<T, E> {
+    pub fn into_map_err_any(self) -> BAnyResult<T> {
+        self.into_map_err(BAnyError::new)
+    }
+}
+
+impl<T> BResult<T, BAnyError> {
+    pub fn into_context(self, context: impl fmt::Display) -> BAnyResult<T> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(mut e) => {
+                e.as_push_context(context);
+                Err(e)
+            }
+        }
+    }
+
+    pub fn into_with_context<C: fmt::Display>(self, context_lazy: impl FnOnce() -> C) -> BAnyResult<T> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(mut e) => {
+                e.as_push_context(context_lazy());
+                Err(e)
+            }
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! bbail {
+    ($($message:tt)+) => {
+        return $crate::betterresult::BResult::Err($crate::betteranyerror::BAnyError::from_message(
+            $crate::betteranyerror::__format!($($message)+),
+        ))
+    };
+}
+
+#[macro_export]
+macro_rules! bensure {
+    ($condition:expr $(,)?) => {
+        if !$condition {
+            $crate::bbail!("condition failed: `{}`", ::core::stringify!($condition));
+        }
+    };
+    ($condition:expr, $($message:tt)+) => {
+        if !$condition {
+            $crate::bbail!($($message)+);
+        }
+    };
+}
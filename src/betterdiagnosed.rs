@@ -0,0 +1,140 @@
+//! ```ignore
+//! BDiagnosed<T, W, E>: a BResult<T, E> that carries warnings W: Accumulator along either way
+//!
+//! new(BResult<T, E>, W) -> BDiagnosed<T, W, E>
+//! from_ok(T) -> BDiagnosed<T, W, E> without warnings
+//! from_err(E) -> BDiagnosed<T, W, E> without warnings
+//! BResult::into_bdiagnosed() -> BDiagnosed<T, W, E> without warnings
+//!
+//! is_ok()       -> bool
+//! is_not_ok()   -> bool
+//! is_err()      -> bool
+//! is_not_err()  -> bool
+//! is_warned()   -> bool
+//! is_not_warned() -> bool
+//!
+//! as_result() -> &BResult<T, E>
+//! as_warnings() -> &W
+//! as_warn(W::Item)
+//! into_warned(W::Item) -> BDiagnosed<T, W, E>
+//!
+//! for <U>: mapping T into U
+//! into_map_ok(|T| U ?Drops T) -> BDiagnosed<U, W, E>
+//! into_map_ok_flatten_lazy(|T| BDiagnosed<U, W, E> ?Drops T) -> BDiagnosed<U, W, E> warnings of both are kept
+//! for <F>: mapping E into F
+//! into_map_err(|E| F ?Drops E) -> BDiagnosed<T, W, F>
+//!
+//! into_promoted(|W| E) -> BResult<T, E> Ok with warnings becomes Err, Drops W on Err
+//! as_drain_warnings() -> W leaves no warnings behind
+//! into_drained() -> (BResult<T, E>, W)
+//! into_bresult() -> BResult<T, E> Drops W
+//! ```
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use crate::bettervalidation::Accumulator;
+use core::mem;
+
+pub struct BDiagnosed<T, W, E> {
+    result: BResult<T, E>,
+    warnings: W,
+}
+
+impl<T, W: Accumulator, E> BDiagnosed<T, W, E> {
+    pub const fn new(result: BResult<T, E>, warnings: W) -> Self {
+        BDiagnosed { result, warnings }
+    }
+
+    pub fn from_ok(value: T) -> Self {
+        BDiagnosed::new(Ok(value), W::default())
+    }
+
+    pub fn from_err(error: E) -> Self {
+        BDiagnosed::new(Err(error), W::default())
+    }
+
+    pub const fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    pub const fn is_not_ok(&self) -> bool {
+        self.result.is_not_ok()
+    }
+
+    pub const fn is_err(&self) -> bool {
+        self.result.is_err()
+    }
+
+    pub const fn is_not_err(&self) -> bool {
+        self.result.is_not_err()
+    }
+
+    pub fn is_warned(&self) -> bool {
+        self.warnings.is_accumulated()
+    }
+
+    pub fn is_not_warned(&self) -> bool {
+        !self.warnings.is_accumulated()
+    }
+
+    pub const fn as_result(&self) -> &BResult<T, E> {
+        &self.result
+    }
+
+    pub const fn as_warnings(&self) -> &W {
+        &self.warnings
+    }
+
+    pub fn as_warn(&mut self, warning: W::Item) {
+        self.warnings.as_accumulate(warning);
+    }
+
+    pub fn into_warned(mut self, warning: W::Item) -> Self {
+        self.as_warn(warning);
+        self
+    }
+
+    pub fn into_map_ok<U>(self, mapper: impl FnOnce(T) -> U) -> BDiagnosed<U, W, E> {
+        BDiagnosed::new(self.result.into_map_ok(mapper), self.warnings)
+    }
+
+    pub fn into_map_ok_flatten_lazy<U>(self, other_if_ok_lazy: impl FnOnce(T) -> BDiagnosed<U, W, E>) -> BDiagnosed<U, W, E> {
+        let BDiagnosed { result, mut warnings } = self;
+        match result {
+            Ok(t) => {
+                let other = other_if_ok_lazy(t);
+                warnings.as_accumulate_all(other.warnings);
+                BDiagnosed::new(other.result, warnings)
+            }
+            Err(e) => BDiagnosed::new(Err(e), warnings),
+        }
+    }
+
+    pub fn into_map_err<F>(self, mapper_err: impl FnOnce(E) -> F) -> BDiagnosed<T, W, F> {
+        BDiagnosed::new(self.result.into_map_err(mapper_err), self.warnings)
+    }
+
+    pub fn into_promoted(self, promote: impl FnOnce(W) -> E) -> BResult<T, E> {
+        match self.result {
+            Ok(_) if self.is_warned() => Err(promote(self.warnings)),
+            result => result,
+        }
+    }
+
+    pub fn as_drain_warnings(&mut self) -> W {
+        mem::take(&mut self.warnings)
+    }
+
+    pub fn into_drained(self) -> (BResult<T, E>, W) {
+        (self.result, self.warnings)
+    }
+
+    pub fn into_bresult(self) -> BResult<T, E> {
+        self.result
+    }
+}
+
+impl<T, E> BResult<T, E> {
+    pub fn into_bdiagnosed<W: Accumulator>(self) -> BDiagnosed<T, W, E> {
+        BDiagnosed::new(self, W::default())
+    }
+}
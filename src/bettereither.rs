@@ -0,0 +1,291 @@
+//! ```ignore
+//! BEither<L, R>
+//!
+//! is_left()      -> bool
+//! is_not_left()  -> bool
+//! is_right()     -> bool
+//! is_not_right() -> bool
+//!
+//! into_is_left_and (|L| bool ?Drops L) -> bool ?Drops R
+//! into_is_right_and(|R| bool ?Drops R) -> bool ?Drops L
+//!
+//! unwrap_left_or(L)                  -> L ?Drops R
+//! unwrap_left_or_else(|R| L ?Drops R) -> L
+//! where L: Default
+//! unwrap_left_or_default()           -> L ?Drops R
+//!
+//! unwrap_right_or(R)                  -> R ?Drops L
+//! unwrap_right_or_else(|L| R ?Drops L) -> R
+//! where R: Default
+//! unwrap_right_or_default()           -> R ?Drops L
+//!
+//! into_boption_left () -> BOption<L> Drops R
+//! into_boption_right() -> BOption<R> Drops L
+//!
+//! as_ref() -> BEither<&L, &R>
+//! as_mut() -> BEither<&mut L, &mut R>
+//!
+//! into_swapped() -> BEither<R, L>
+//!
+//! for <M>: mapping L into M
+//! into_map_left (|L| M ?Drops L) -> BEither<M, R>
+//! for <S>: mapping R into S
+//! into_map_right(|R| S ?Drops R) -> BEither<L, S>
+//! into_map_both (|L| M ?Drops L, |R| S ?Drops R) -> BEither<M, S>
+//! for <U>: mapping L or R into U
+//! into_map_left_or_else(|L| U ?Drops L, |R| U ?Drops R) -> U
+//!
+//! for <M>: mapping L into BEither<M, R>
+//! into_map_left_flatten_lazy (|L| BEither<M, R> ?Drops L) -> BEither<M, R>
+//! for <S>: mapping R into BEither<L, S>
+//! into_map_right_flatten_lazy(|R| BEither<L, S> ?Drops R) -> BEither<L, S>
+//!
+//! into_self_inspect_left (|&L|) -> BEither<L, R>
+//! into_self_inspect_right(|&R|) -> BEither<L, R>
+//! as_inspect_left (|&L|)
+//! as_inspect_right(|&R|)
+//!
+//! from_bresult(BResult<L, R>) -> BEither<L, R> Ok is Left
+//! into_bresult() -> BResult<L, R> Left is Ok
+//! BResult::into_beither() -> BEither<T, E>
+//!
+//! where L = R = T
+//! into_inner() -> T
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+
+pub enum BEither<L, R> {
+    Left(L),
+    Right(R),
+}
+use BEither::{Left, Right};
+
+impl<L, R> BEither<L, R> {
+    pub const fn is_left(&self) -> bool {
+        match *self {
+            Left(_) => true,
+            Right(_) => false,
+        }
+    }
+
+    pub const fn is_not_left(&self) -> bool {
+        match *self {
+            Left(_) => false,
+            Right(_) => true,
+        }
+    }
+
+    pub const fn is_right(&self) -> bool {
+        match *self {
+            Left(_) => false,
+            Right(_) => true,
+        }
+    }
+
+    pub const fn is_not_right(&self) -> bool {
+        match *self {
+            Left(_) => true,
+            Right(_) => false,
+        }
+    }
+
+    pub fn into_is_left_and(self, cond: impl FnOnce(L) -> bool) -> bool {
+        match self {
+            Left(l) => cond(l),
+            Right(_) => false,
+        }
+    }
+
+    pub fn into_is_right_and(self, cond: impl FnOnce(R) -> bool) -> bool {
+        match self {
+            Left(_) => false,
+            Right(r) => cond(r),
+        }
+    }
+
+    pub fn unwrap_left_or(self, default_eager: L) -> L {
+        match self {
+            Left(l) => l,
+            Right(_) => default_eager,
+        }
+    }
+
+    pub fn unwrap_left_or_else(self, default_lazy: impl FnOnce(R) -> L) -> L {
+        match self {
+            Left(l) => l,
+            Right(r) => default_lazy(r),
+        }
+    }
+
+    pub fn unwrap_left_or_default(self) -> L
+    where
+        L: Default,
+    {
+        match self {
+            Left(l) => l,
+            Right(_) => L::default(),
+        }
+    }
+
+    pub fn unwrap_right_or(self, default_eager: R) -> R {
+        match self {
+            Left(_) => default_eager,
+            Right(r) => r,
+        }
+    }
+
+    pub fn unwrap_right_or_else(self, default_lazy: impl FnOnce(L) -> R) -> R {
+        match self {
+            Left(l) => default_lazy(l),
+            Right(r) => r,
+        }
+    }
+
+    pub fn unwrap_right_or_default(self) -> R
+    where
+        R: Default,
+    {
+        match self {
+            Left(_) => R::default(),
+            Right(r) => r,
+        }
+    }
+
+    pub fn into_boption_left(self) -> BOption<L> {
+        match self {
+            Left(l) => Some(l),
+            Right(_) => None,
+        }
+    }
+
+    pub fn into_boption_right(self) -> BOption<R> {
+        match self {
+            Left(_) => None,
+            Right(r) => Some(r),
+        }
+    }
+
+    pub const fn as_ref(&self) -> BEither<&L, &R> {
+        match *self {
+            Left(ref l) => Left(l),
+            Right(ref r) => Right(r),
+        }
+    }
+
+    pub const fn as_mut(&mut self) -> BEither<&mut L, &mut R> {
+        match *self {
+            Left(ref mut l) => Left(l),
+            Right(ref mut r) => Right(r),
+        }
+    }
+
+    pub fn into_swapped(self) -> BEither<R, L> {
+        match self {
+            Left(l) => Right(l),
+            Right(r) => Left(r),
+        }
+    }
+
+    pub fn into_map_left<M>(self, mapper_left: impl FnOnce(L) -> M) -> BEither<M, R> {
+        match self {
+            Left(l) => Left(mapper_left(l)),
+            Right(r) => Right(r),
+        }
+    }
+
+    pub fn into_map_right<S>(self, mapper_right: impl FnOnce(R) -> S) -> BEither<L, S> {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => Right(mapper_right(r)),
+        }
+    }
+
+    pub fn into_map_both<M, S>(self, mapper_left: impl FnOnce(L) -> M, mapper_right: impl FnOnce(R) -> S) -> BEither<M, S> {
+        match self {
+            Left(l) => Left(mapper_left(l)),
+            Right(r) => Right(mapper_right(r)),
+        }
+    }
+
+    pub fn into_map_left_or_else<U>(self, mapper_left: impl FnOnce(L) -> U, mapper_right: impl FnOnce(R) -> U) -> U {
+        match self {
+            Left(l) => mapper_left(l),
+            Right(r) => mapper_right(r),
+        }
+    }
+
+    pub fn into_map_left_flatten_lazy<M>(self, other_if_left_lazy: impl FnOnce(L) -> BEither<M, R>) -> BEither<M, R> {
+        match self {
+            Left(l) => other_if_left_lazy(l),
+            Right(r) => Right(r),
+        }
+    }
+
+    pub fn into_map_right_flatten_lazy<S>(self, other_if_right_lazy: impl FnOnce(R) -> BEither<L, S>) -> BEither<L, S> {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => other_if_right_lazy(r),
+        }
+    }
+
+    pub fn into_self_inspect_left(self, inspector_left: impl FnOnce(&L)) -> BEither<L, R> {
+        match self {
+            Left(ref l) => inspector_left(l),
+            Right(_) => {}
+        }
+        self
+    }
+
+    pub fn into_self_inspect_right(self, inspector_right: impl FnOnce(&R)) -> BEither<L, R> {
+        match self {
+            Left(_) => {}
+            Right(ref r) => inspector_right(r),
+        }
+        self
+    }
+
+    pub fn as_inspect_left(&self, inspector_left: impl FnOnce(&L)) {
+        match *self {
+            Left(ref l) => inspector_left(l),
+            Right(_) => {}
+        }
+    }
+
+    pub fn as_inspect_right(&self, inspector_right: impl FnOnce(&R)) {
+        match *self {
+            Left(_) => {}
+            Right(ref r) => inspector_right(r),
+        }
+    }
+
+    pub fn from_bresult(result: BResult<L, R>) -> Self {
+        match result {
+            Ok(l) => Left(l),
+            Err(r) => Right(r),
+        }
+    }
+
+    pub fn into_bresult(self) -> BResult<L, R> {
+        match self {
+            Left(l) => Ok(l),
+            Right(r) => Err(r),
+        }
+    }
+}
+
+impl<T> BEither<T, T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            Left(t) | Right(t) => t,
+        }
+    }
+}
+
+impl<T, E> BResult<T, E> {
+    pub fn into_beither(self) -> BEither<T, E> {
+        BEither::from_bresult(self)
+    }
+}
@@ -0,0 +1,107 @@
+//! ```ignore
+//! BOption::as_entry() -> BOptionEntry<'a, T>: Occupied(OccupiedEntry) or Vacant(VacantEntry)
+//!
+//! BOptionEntry<'a, T>
+//! or_insert(T) -> &'a mut T ?Drops T if occupied
+//! or_insert_with(|| T) -> &'a mut T
+//! where T: Default
+//! or_default() -> &'a mut T
+//! and_modify(|&mut T|) -> BOptionEntry<'a, T>
+//!
+//! OccupiedEntry<'a, T>
+//! get() -> &T
+//! get_mut() -> &mut T
+//! into_mut() -> &'a mut T
+//! insert(T) -> T the old value
+//! remove() -> T leaves None behind
+//!
+//! VacantEntry<'a, T>
+//! insert(T) -> &'a mut T
+//! ```
+use crate::betteroption::BOption;
+use core::mem;
+
+pub enum BOptionEntry<'a, T> {
+    Occupied(OccupiedEntry<'a, T>),
+    Vacant(VacantEntry<'a, T>),
+}
+use BOptionEntry::{Occupied, Vacant};
+
+pub struct OccupiedEntry<'a, T> {
+    slot: &'a mut BOption<T>,
+}
+
+pub struct VacantEntry<'a, T> {
+    slot: &'a mut BOption<T>,
+}
+
+impl<T> BOption<T> {
+    pub fn as_entry(&mut self) -> BOptionEntry<'_, T> {
+        if self.is_some() {
+            Occupied(OccupiedEntry { slot: self })
+        } else {
+            Vacant(VacantEntry { slot: self })
+        }
+    }
+}
+
+impl<'a, T> BOptionEntry<'a, T> {
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        match self {
+            Occupied(occupied) => occupied.into_mut(),
+            Vacant(vacant) => vacant.insert(default),
+        }
+    }
+
+    pub fn or_insert_with(self, default_lazy: impl FnOnce() -> T) -> &'a mut T {
+        match self {
+            Occupied(occupied) => occupied.into_mut(),
+            Vacant(vacant) => vacant.slot.as_insert_or_else(default_lazy),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut T
+    where
+        T: Default,
+    {
+        self.or_insert_with(T::default)
+    }
+
+    pub fn and_modify(self, modifier: impl FnOnce(&mut T)) -> Self {
+        match self {
+            Occupied(mut occupied) => {
+                modifier(occupied.get_mut());
+                Occupied(occupied)
+            }
+            Vacant(vacant) => Vacant(vacant),
+        }
+    }
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    pub fn get(&self) -> &T {
+        unsafe { self.slot.as_ref().unwrap_unchecked() }
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe { self.slot.as_mut().unwrap_unchecked() }
+    }
+
+    pub fn into_mut(self) -> &'a mut T {
+        unsafe { self.slot.as_mut().unwrap_unchecked() }
+    }
+
+    pub fn insert(&mut self, value: T) -> T {
+        mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> T {
+        unsafe { self.slot.as_take().unwrap_unchecked() }
+    }
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    pub fn insert(self, value: T) -> &'a mut T {
+        self.slot.as_insert_or_else(|| value)
+    }
+}
@@ -0,0 +1,132 @@
+//! ```ignore
+//! BError<E, N>: a source error plus up to N `&'static str` context frames, no allocator needed
+//!
+//! new(E) -> BError<E, N>
+//! as_source() -> &E
+//! into_source() -> E
+//! as_context() -> &[ContextFrame] innermost first
+//! overflowed() -> usize
+//! as_push_context(&'static str) ?records caller location
+//!
+//! BResult<T, E>
+//! into_berror() -> BResult<T, BError<E, N>> the one place N is chosen, no frame is added
+//!
+//! BResult<T, BError<E, N>>
+//! into_context(&'static str) -> BResult<T, BError<E, N>> ?records caller location
+//! into_context_lazy(|| &'static str) -> BResult<T, BError<E, N>> ?records caller location
+//! past N frames the innermost ones are kept, outer frames are silently dropped and only counted in overflowed()
+//!
+//! load().into_berror::<4>().into_context("loading config").into_context("starting app")
+//! for formatted context strings, go through `into_map_err_any()` and BAnyResult::into_context instead
+//!
+//! Display: `outermost context: ...: innermost context: source`
+//! ```
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use crate::betterstack::BStack;
+use core::error::Error;
+use core::fmt;
+use core::panic::Location;
+
+#[derive(Debug, Copy, Clone)]
+pub struct ContextFrame {
+    pub message: &'static str,
+    pub location: &'static Location<'static>,
+}
+
+pub struct BError<E, const N: usize> {
+    source: E,
+    context: BStack<ContextFrame, N>,
+}
+
+impl<E, const N: usize> BError<E, N> {
+    pub const fn new(source: E) -> Self {
+        BError {
+            source,
+            context: BStack::new(),
+        }
+    }
+
+    pub const fn as_source(&self) -> &E {
+        &self.source
+    }
+
+    pub fn into_source(self) -> E {
+        self.source
+    }
+
+    pub fn as_context(&self) -> &[ContextFrame] {
+        self.context.as_slice()
+    }
+
+    pub const fn overflowed(&self) -> usize {
+        self.context.overflowed()
+    }
+
+    #[track_caller]
+    pub fn as_push_context(&mut self, message: &'static str) {
+        let frame = ContextFrame {
+            message,
+            location: Location::caller(),
+        };
+        let _ = self.context.as_push(frame);
+    }
+}
+
+impl<E: fmt::Display, const N: usize> fmt::Display for BError<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for frame in self.context.as_iter().rev() {
+            write!(f, "{}: ", frame.message)?;
+        }
+        write!(f, "{}", self.source)
+    }
+}
+
+impl<E: fmt::Debug, const N: usize> fmt::Debug for BError<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BError")
+            .field("source", &self.source)
+            .field("context", &self.context)
+            .field("overflowed", &self.context.overflowed())
+            .finish()
+    }
+}
+
+impl<E: Error + 'static, const N: usize> Error for BError<E, N> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Option::Some(&self.source)
+    }
+}
+
+impl<T, E> BResult<T, E> {
+    pub fn into_berror<const N: usize>(self) -> BResult<T, BError<E, N>> {
+        self.into_map_err(BError::new)
+    }
+}
+
+impl<T, E, const N: usize> BResult<T, BError<E, N>> {
+    #[track_caller]
+    pub fn into_context(self, message: &'static str) -> BResult<T, BError<E, N>> {
+        let location = Location::caller();
+        match self {
+            Ok(t) => Ok(t),
+            Err(mut error) => {
+                let _ = error.context.as_push(ContextFrame { message, location });
+                Err(error)
+            }
+        }
+    }
+
+    #[track_caller]
+    pub fn into_context_lazy(self, message_lazy: impl FnOnce() -> &'static str) -> BResult<T, BError<E, N>> {
+        let location = Location::caller();
+        match self {
+            Ok(t) => Ok(t),
+            Err(mut error) => {
+                let message = message_lazy();
+                let _ = error.context.as_push(ContextFrame { message, location });
+                Err(error)
+            }
+        }
+    }
+}
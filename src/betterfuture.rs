@@ -0,0 +1,228 @@
+//! ```ignore
+//! Futures inside BOption and BResult, awaited without matching first
+//!
+//! where T: Future
+//! BOption::into_transposed_future() -> BOptionFuture<T> yields BOption<T::Output>, None is ready at once
+//! for <Fut>: Fut: Future
+//! BOption::into_map_async(|T| Fut ?Drops T) -> BOptionFuture<Fut>
+//!
+//! where T: Future
+//! BResult::into_transposed_future() -> BResultFuture<T, E> yields BResult<T::Output, E>, Err is ready at once
+//! where E: Future
+//! BResult::into_transposed_err_future() -> BResultErrFuture<T, E> yields BResult<T, E::Output>, Ok is ready at once
+//! for <Fut>: Fut: Future
+//! BResult::into_map_ok_async (|T| Fut ?Drops T) -> BResultFuture<Fut, E>
+//! BResult::into_map_err_async(|E| Fut ?Drops E) -> BResultErrFuture<T, Fut>
+//!
+//! polling a BResultFuture or BResultErrFuture again after it yielded its ready side stays Pending
+//!
+//! where T = Poll<U>
+//! BOption::into_transposed_poll() -> Poll<BOption<U>>
+//! BOption::from_transposed_poll(Poll<BOption<U>>) -> BOption<Poll<U>> Pending is Some(Pending)
+//! BResult::into_transposed_poll() -> Poll<BResult<U, E>>
+//! BResult::from_transposed_poll(Poll<BResult<U, E>>) -> BResult<Poll<U>, E> Pending is Ok(Pending)
+//!
+//! bready!(Poll<BOption<T>>) -> T returns Pending or Ready(None) from the enclosing poll function
+//! bready!(Poll<BResult<T, E>>) -> T returns Pending or Ready(Err(E)) from the enclosing poll function
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use core::convert::Infallible;
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+pub struct BOptionFuture<F> {
+    future: BOption<F>,
+}
+
+impl<F: Future> Future for BOptionFuture<F> {
+    type Output = BOption<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<BOption<F::Output>> {
+        match unsafe { self.get_unchecked_mut() }.future {
+            Some(ref mut future) => unsafe { Pin::new_unchecked(future) }.poll(cx).map(Some),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+pub struct BResultFuture<F, E> {
+    future: BResult<F, BOption<E>>,
+}
+
+impl<F: Future, E> Future for BResultFuture<F, E> {
+    type Output = BResult<F::Output, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<BResult<F::Output, E>> {
+        match unsafe { self.get_unchecked_mut() }.future {
+            Ok(ref mut future) => unsafe { Pin::new_unchecked(future) }.poll(cx).map(Ok),
+            Err(ref mut e) => match e.as_take() {
+                Some(e) => Poll::Ready(Err(e)),
+                None => Poll::Pending,
+            },
+        }
+    }
+}
+
+pub struct BResultErrFuture<T, F> {
+    future: BResult<BOption<T>, F>,
+}
+
+impl<T, F: Future> Future for BResultErrFuture<T, F> {
+    type Output = BResult<T, F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<BResult<T, F::Output>> {
+        match unsafe { self.get_unchecked_mut() }.future {
+            Ok(ref mut t) => match t.as_take() {
+                Some(t) => Poll::Ready(Ok(t)),
+                None => Poll::Pending,
+            },
+            Err(ref mut future) => unsafe { Pin::new_unchecked(future) }.poll(cx).map(Err),
+        }
+    }
+}
+
+impl<T: Future> BOption<T> {
+    pub fn into_transposed_future(self) -> BOptionFuture<T> {
+        BOptionFuture { future: self }
+    }
+}
+
+impl<T> BOption<T> {
+    pub fn into_map_async<Fut: Future>(self, mapper: impl FnOnce(T) -> Fut) -> BOptionFuture<Fut> {
+        self.into_map(mapper).into_transposed_future()
+    }
+}
+
+impl<T: Future, E> BResult<T, E> {
+    pub fn into_transposed_future(self) -> BResultFuture<T, E> {
+        BResultFuture { future: self.into_map_err(Some) }
+    }
+}
+
+impl<T, E: Future> BResult<T, E> {
+    pub fn into_transposed_err_future(self) -> BResultErrFuture<T, E> {
+        BResultErrFuture { future: self.into_map_ok(Some) }
+    }
+}
+
+impl<T, E> BResult<T, E> {
+    pub fn into_map_ok_async<Fut: Future>(self, mapper: impl FnOnce(T) -> Fut) -> BResultFuture<Fut, E> {
+        self.into_map_ok(mapper).into_transposed_future()
+    }
+
+    pub fn into_map_err_async<Fut: Future>(self, mapper_err: impl FnOnce(E) -> Fut) -> BResultErrFuture<T, Fut> {
+        self.into_map_err(mapper_err).into_transposed_err_future()
+    }
+}
+
+impl<T> BOption<Poll<T>> {
+    pub fn into_transposed_poll(self) -> Poll<BOption<T>> {
+        match self {
+            Some(Poll::Ready(t)) => Poll::Ready(Some(t)),
+            Some(Poll::Pending) => Poll::Pending,
+            None => Poll::Ready(None),
+        }
+    }
+
+    pub fn from_transposed_poll(poll: Poll<BOption<T>>) -> Self {
+        match poll {
+            Poll::Ready(Some(t)) => Some(Poll::Ready(t)),
+            Poll::Ready(None) => None,
+            Poll::Pending => Some(Poll::Pending),
+        }
+    }
+}
+
+impl<T, E> BResult<Poll<T>, E> {
+    pub fn into_transposed_poll(self) -> Poll<BResult<T, E>> {
+        match self {
+            Ok(Poll::Ready(t)) => Poll::Ready(Ok(t)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+
+    pub fn from_transposed_poll(poll: Poll<BResult<T, E>>) -> Self {
+        match poll {
+            Poll::Ready(Ok(t)) => Ok(Poll::Ready(t)),
+            Poll::Ready(Err(e)) => Err(e),
+            Poll::Pending => Ok(Poll::Pending),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait BReadyBranch {
+    type Output;
+    type Residual;
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+#[doc(hidden)]
+pub trait BReadyResidual<R> {
+    fn from_residual(residual: R) -> Self;
+}
+
+impl<T> BReadyBranch for Poll<BOption<T>> {
+    type Output = T;
+    type Residual = Poll<BOption<Infallible>>;
+
+    fn branch(self) -> ControlFlow<Poll<BOption<Infallible>>, T> {
+        match self {
+            Poll::Ready(Some(t)) => ControlFlow::Continue(t),
+            Poll::Ready(None) => ControlFlow::Break(Poll::Ready(None)),
+            Poll::Pending => ControlFlow::Break(Poll::Pending),
+        }
+    }
+}
+
+impl<T> BReadyResidual<Poll<BOption<Infallible>>> for Poll<BOption<T>> {
+    fn from_residual(residual: Poll<BOption<Infallible>>) -> Self {
+        match residual {
+            Poll::Ready(Some(never)) => match never {},
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<T, E> BReadyBranch for Poll<BResult<T, E>> {
+    type Output = T;
+    type Residual = Poll<BResult<Infallible, E>>;
+
+    fn branch(self) -> ControlFlow<Poll<BResult<Infallible, E>>, T> {
+        match self {
+            Poll::Ready(Ok(t)) => ControlFlow::Continue(t),
+            Poll::Ready(Err(e)) => ControlFlow::Break(Poll::Ready(Err(e))),
+            Poll::Pending => ControlFlow::Break(Poll::Pending),
+        }
+    }
+}
+
+impl<T, E> BReadyResidual<Poll<BResult<Infallible, E>>> for Poll<BResult<T, E>> {
+    fn from_residual(residual: Poll<BResult<Infallible, E>>) -> Self {
+        match residual {
+            Poll::Ready(Ok(never)) => match never {},
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! bready {
+    ($poll:expr $(,)?) => {
+        match $crate::betterfuture::BReadyBranch::branch($poll) {
+            ::core::ops::ControlFlow::Continue(t) => t,
+            ::core::ops::ControlFlow::Break(residual) => {
+                return $crate::betterfuture::BReadyResidual::from_residual(residual);
+            }
+        }
+    };
+}
@@ -0,0 +1,102 @@
+//! ```ignore
+//! set_unwrap_failure_hook(fn(&UnwrapFailure))
+//! take_unwrap_failure_hook() -> BOption<fn(&UnwrapFailure)>
+//!
+//! the hook runs right before a failed unwrap/expect of BOption or BResult panics,
+//! e.g. to log the failure on targets where a panic resets the device.
+//!
+//! plain unwrap/expect never print the payload they found, since that needs it to be Debug,
+//! the *_debug variants add the bound and print it.
+//! the *_with variants are called as `expect_with(|f| write!(f, "no user {id}"))`:
+//! a closure can't return format_args! built from its captures, so it writes into the formatter instead.
+//! setting and calling the hook only need atomic load/store, so it works on targets without compare-and-swap,
+//! there take_unwrap_failure_hook is a load followed by a store and may race with a concurrent set.
+//!
+//! UnwrapFailure
+//!
+//! message() -> fmt::Arguments
+//! location() -> &'static Location<'static>
+//! found() -> UnwrapVariant the variant that was found instead of the expected one
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use core::fmt;
+use core::mem;
+use core::panic::Location;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UnwrapVariant {
+    Some,
+    None,
+    Ok,
+    Err,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct UnwrapFailure<'a> {
+    message: fmt::Arguments<'a>,
+    location: &'static Location<'static>,
+    found: UnwrapVariant,
+}
+
+impl<'a> UnwrapFailure<'a> {
+    #[cfg(not(feature = "no-panic"))]
+    pub(crate) const fn new(message: fmt::Arguments<'a>, location: &'static Location<'static>, found: UnwrapVariant) -> Self {
+        UnwrapFailure { message, location, found }
+    }
+
+    pub const fn message(&self) -> fmt::Arguments<'a> {
+        self.message
+    }
+
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    pub const fn found(&self) -> UnwrapVariant {
+        self.found
+    }
+}
+
+impl fmt::Display for UnwrapFailure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unwrap failed at {}: {}", self.location, self.message)
+    }
+}
+
+pub type UnwrapFailureHook = fn(&UnwrapFailure<'_>);
+
+static UNWRAP_FAILURE_HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+fn hook_from_ptr(hook: *mut ()) -> BOption<UnwrapFailureHook> {
+    if hook.is_null() {
+        None
+    } else {
+        Some(unsafe { mem::transmute::<*mut (), UnwrapFailureHook>(hook) })
+    }
+}
+
+pub fn set_unwrap_failure_hook(hook: UnwrapFailureHook) {
+    UNWRAP_FAILURE_HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+pub fn take_unwrap_failure_hook() -> BOption<UnwrapFailureHook> {
+    #[cfg(target_has_atomic = "ptr")]
+    let hook = UNWRAP_FAILURE_HOOK.swap(ptr::null_mut(), Ordering::AcqRel);
+    #[cfg(not(target_has_atomic = "ptr"))]
+    let hook = {
+        let hook = UNWRAP_FAILURE_HOOK.load(Ordering::Acquire);
+        UNWRAP_FAILURE_HOOK.store(ptr::null_mut(), Ordering::Release);
+        hook
+    };
+    hook_from_ptr(hook)
+}
+
+#[cfg(not(feature = "no-panic"))]
+pub(crate) fn call_unwrap_failure_hook(failure: &UnwrapFailure<'_>) {
+    if let Some(hook) = hook_from_ptr(UNWRAP_FAILURE_HOOK.load(Ordering::Acquire)) {
+        hook(failure);
+    }
+}
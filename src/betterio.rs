@@ -0,0 +1,71 @@
+//! ```ignore
+//! BIoResult<T> = BResult<T, io::Error>
+//!
+//! is_err_kind(ErrorKind) -> bool
+//! is_not_err_kind(ErrorKind) -> bool
+//! into_boption_err_kind() -> BOption<ErrorKind> Drops T
+//!
+//! into_recover_kind(ErrorKind, |io::Error| T ?Drops io::Error) -> BIoResult<T>
+//! into_ok_if_kind(ErrorKind) -> BIoResult<BOption<T>> ?Drops io::Error
+//! into_ok_if_not_found() -> BIoResult<BOption<T>> ?Drops io::Error
+//!
+//! into_retry_if_interrupted(|| BIoResult<T>) -> BIoResult<T>
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use std::io::{self, ErrorKind};
+
+pub type BIoResult<T> = BResult<T, io::Error>;
+
+impl<T> BResult<T, io::Error> {
+    pub fn is_err_kind(&self, kind: ErrorKind) -> bool {
+        match *self {
+            Ok(_) => false,
+            Err(ref e) => e.kind() == kind,
+        }
+    }
+
+    pub fn is_not_err_kind(&self, kind: ErrorKind) -> bool {
+        match *self {
+            Ok(_) => true,
+            Err(ref e) => e.kind() != kind,
+        }
+    }
+
+    pub fn into_boption_err_kind(self) -> BOption<ErrorKind> {
+        match self {
+            Ok(_) => None,
+            Err(e) => Some(e.kind()),
+        }
+    }
+
+    pub fn into_recover_kind(self, kind: ErrorKind, recover: impl FnOnce(io::Error) -> T) -> BIoResult<T> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(e) if e.kind() == kind => Ok(recover(e)),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn into_ok_if_kind(self, kind: ErrorKind) -> BIoResult<BOption<T>> {
+        match self {
+            Ok(t) => Ok(Some(t)),
+            Err(e) if e.kind() == kind => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn into_ok_if_not_found(self) -> BIoResult<BOption<T>> {
+        self.into_ok_if_kind(ErrorKind::NotFound)
+    }
+
+    pub fn into_retry_if_interrupted(self, mut retry: impl FnMut() -> BIoResult<T>) -> BIoResult<T> {
+        let mut current = self;
+        while current.is_err_kind(ErrorKind::Interrupted) {
+            current = retry();
+        }
+        current
+    }
+}
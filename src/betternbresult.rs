@@ -0,0 +1,199 @@
+//! ```ignore
+//! BNbResult<T, E>: Ok(T), WouldBlock or Err(E), for non-blocking operations that are polled until done
+//!
+//! is_ok()                -> bool
+//! is_not_ok()            -> bool
+//! is_would_block()       -> bool
+//! is_not_would_block()   -> bool
+//! is_err()               -> bool
+//! is_not_err()           -> bool
+//!
+//! as_ref() -> BNbResult<&T, &E>
+//! as_mut() -> BNbResult<&mut T, &mut E>
+//!
+//! into_boption_ok () -> BOption<T> Drops E
+//! into_boption_err() -> BOption<E> Drops T
+//!
+//! for <U>: mapping T into U
+//! into_map_ok(|T| U ?Drops T) -> BNbResult<U, E>
+//! into_map_ok_flatten_lazy(|T| BNbResult<U, E> ?Drops T) -> BNbResult<U, E>
+//! for <F>: mapping E into F
+//! into_map_err(|E| F ?Drops E) -> BNbResult<T, F>
+//!
+//! from_bresult(BResult<T, E>) -> BNbResult<T, E> never WouldBlock
+//! into_bresult() -> BResult<BOption<T>, E> WouldBlock is Ok(None)
+//! into_bresult_or(E) -> BResult<T, E> WouldBlock is the given error, Drops E
+//! into_bresult_or_else(|| E) -> BResult<T, E>
+//!
+//! from_poll(Poll<BResult<T, E>>) -> BNbResult<T, E> Pending is WouldBlock
+//! into_poll() -> Poll<BResult<T, E>> WouldBlock is Pending
+//!
+//! bblock!(BNbResult<T, E>) -> BResult<T, E> re-evaluates the expression until it stops blocking
+//! ```
+use crate::betteroption::BOption;
+use crate::betterresult::BResult;
+use core::task::Poll;
+
+pub enum BNbResult<T, E> {
+    Ok(T),
+    WouldBlock,
+    Err(E),
+}
+use BNbResult::{Err, Ok, WouldBlock};
+
+impl<T, E> BNbResult<T, E> {
+    pub const fn is_ok(&self) -> bool {
+        match *self {
+            Ok(_) => true,
+            WouldBlock | Err(_) => false,
+        }
+    }
+
+    pub const fn is_not_ok(&self) -> bool {
+        match *self {
+            Ok(_) => false,
+            WouldBlock | Err(_) => true,
+        }
+    }
+
+    pub const fn is_would_block(&self) -> bool {
+        match *self {
+            WouldBlock => true,
+            Ok(_) | Err(_) => false,
+        }
+    }
+
+    pub const fn is_not_would_block(&self) -> bool {
+        match *self {
+            WouldBlock => false,
+            Ok(_) | Err(_) => true,
+        }
+    }
+
+    pub const fn is_err(&self) -> bool {
+        match *self {
+            Err(_) => true,
+            Ok(_) | WouldBlock => false,
+        }
+    }
+
+    pub const fn is_not_err(&self) -> bool {
+        match *self {
+            Err(_) => false,
+            Ok(_) | WouldBlock => true,
+        }
+    }
+
+    pub const fn as_ref(&self) -> BNbResult<&T, &E> {
+        match *self {
+            Ok(ref t) => Ok(t),
+            WouldBlock => WouldBlock,
+            Err(ref e) => Err(e),
+        }
+    }
+
+    pub const fn as_mut(&mut self) -> BNbResult<&mut T, &mut E> {
+        match *self {
+            Ok(ref mut t) => Ok(t),
+            WouldBlock => WouldBlock,
+            Err(ref mut e) => Err(e),
+        }
+    }
+
+    pub fn into_boption_ok(self) -> BOption<T> {
+        match self {
+            Ok(t) => BOption::Some(t),
+            WouldBlock | Err(_) => BOption::None,
+        }
+    }
+
+    pub fn into_boption_err(self) -> BOption<E> {
+        match self {
+            Err(e) => BOption::Some(e),
+            Ok(_) | WouldBlock => BOption::None,
+        }
+    }
+
+    pub fn into_map_ok<U>(self, mapper: impl FnOnce(T) -> U) -> BNbResult<U, E> {
+        match self {
+            Ok(t) => Ok(mapper(t)),
+            WouldBlock => WouldBlock,
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn into_map_ok_flatten_lazy<U>(self, other_if_ok_lazy: impl FnOnce(T) -> BNbResult<U, E>) -> BNbResult<U, E> {
+        match self {
+            Ok(t) => other_if_ok_lazy(t),
+            WouldBlock => WouldBlock,
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn into_map_err<F>(self, mapper_err: impl FnOnce(E) -> F) -> BNbResult<T, F> {
+        match self {
+            Ok(t) => Ok(t),
+            WouldBlock => WouldBlock,
+            Err(e) => Err(mapper_err(e)),
+        }
+    }
+
+    pub fn from_bresult(result: BResult<T, E>) -> Self {
+        match result {
+            BResult::Ok(t) => Ok(t),
+            BResult::Err(e) => Err(e),
+        }
+    }
+
+    pub fn into_bresult(self) -> BResult<BOption<T>, E> {
+        match self {
+            Ok(t) => BResult::Ok(BOption::Some(t)),
+            WouldBlock => BResult::Ok(BOption::None),
+            Err(e) => BResult::Err(e),
+        }
+    }
+
+    pub fn into_bresult_or(self, would_block_eager: E) -> BResult<T, E> {
+        match self {
+            Ok(t) => BResult::Ok(t),
+            WouldBlock => BResult::Err(would_block_eager),
+            Err(e) => BResult::Err(e),
+        }
+    }
+
+    pub fn into_bresult_or_else(self, would_block_lazy: impl FnOnce() -> E) -> BResult<T, E> {
+        match self {
+            Ok(t) => BResult::Ok(t),
+            WouldBlock => BResult::Err(would_block_lazy()),
+            Err(e) => BResult::Err(e),
+        }
+    }
+
+    pub fn from_poll(poll: Poll<BResult<T, E>>) -> Self {
+        match poll {
+            Poll::Ready(result) => BNbResult::from_bresult(result),
+            Poll::Pending => WouldBlock,
+        }
+    }
+
+    pub fn into_poll(self) -> Poll<BResult<T, E>> {
+        match self {
+            Ok(t) => Poll::Ready(BResult::Ok(t)),
+            WouldBlock => Poll::Pending,
+            Err(e) => Poll::Ready(BResult::Err(e)),
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! bblock {
+    ($nb_result:expr $(,)?) => {
+        loop {
+            match $nb_result {
+                $crate::betternbresult::BNbResult::Ok(t) => break $crate::betterresult::BResult::Ok(t),
+                $crate::betternbresult::BNbResult::WouldBlock => ::core::hint::spin_loop(),
+                $crate::betternbresult::BNbResult::Err(e) => break $crate::betterresult::BResult::Err(e),
+            }
+        }
+    };
+}
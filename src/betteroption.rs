@@ -9,13 +9,22 @@
 //!
 //! is_niche_optimized() -> bool
 //!
+//! into_is_some_and(|T| bool ?Drops T) -> bool
+//! into_is_none_or (|T| bool ?Drops T) -> bool
+//! as_is_some_and(|&T| bool) -> bool
+//! as_is_none_or (|&T| bool) -> bool
+//! where T: PartialEq
+//! as_contains(&T) -> bool
+//!
 //! unwrap()             -> T ?panic
 //! unwrap_or(T)         -> T
 //! unwrap_or_else(|| T) -> T
 //! where T: Default
 //! unwrap_or_default()  -> T
 //!
-//! unwrap_none()                        -> () ?panic
+//! unwrap_none()                        -> () ?panic without T, see unwrap_none_debug
+//! where T: Debug
+//! unwrap_none_debug()                  -> () ?panic with T's Debug
 //! unwrap_none_or()                     -> () ?Drops T
 //! unwrap_none_or_else(|T| () ?Drops T) -> ()
 //! unwrap_none_or_default()             -> () ?Drops T
@@ -23,12 +32,32 @@
 //! where S: AsRef<str>
 //! expect(S)      -> T ?panic
 //! expect_none(S) -> () ?Drops T + panic
+//! where S: AsRef<str>, T: Debug
+//! expect_none_debug(S) -> () ?panic with T's Debug
+//!
+//! expect_with(|&mut Formatter| fmt::Result)      -> T ?panic, message formatted only on failure
+//! expect_none_with(|&mut Formatter| fmt::Result) -> () ?Drops T + panic, message formatted only on failure
+//! see betterhook for how to call the *_with variants and why plain unwrap/expect omit the payload
+//!
+//! every panicking method reports the caller's location
+//! and is compiled out by the `no-panic` feature
 //!
 //! unsafe unwrap_unchecked()      -> T ?ub
 //! unsafe unwrap_none_unchecked() -> () ?ub
+//! ?ub panics with the method name and caller location when debug assertions are on,
+//! FfiOption's tag is checked the same way before it is read,
+//! both stay in debug builds even with `no-panic`, release builds never check
 //!
 //! as_ref() -> BOption<&T>
 //! as_mut() -> BOption<&mut T>
+//! where T: Deref
+//! as_deref() -> BOption<&T::Target>
+//! where T: DerefMut
+//! as_deref_mut() -> BOption<&mut T::Target>
+//! as_pin_ref(self: Pin<&Self>) -> BOption<Pin<&T>>
+//! as_pin_mut(self: Pin<&mut Self>) -> BOption<Pin<&mut T>>
+//! as_slice() -> &[T] with 0 or 1 elements
+//! as_mut_slice() -> &mut [T] with 0 or 1 elements
 //! 
 //! into_option() -> Option<T>
 //! into_ffi_option() -> FfiOption<T>
@@ -57,6 +86,7 @@
 //! where T: Default
 //! into_bresult_err_or_default() -> BResult<T, E>
 //!
+//! BOption<&T> or BOption<&mut T>
 //! where T: Clone
 //! into_cloned() -> BOption<T>
 //! where T: Copy
@@ -78,24 +108,53 @@
 //! into_collect_lazy(|| BOption<T>) -> BOption<T>
 //! into_xor(BOption<T>) -> BOption<T>
 //!
+//! for <U>: true is Some
+//! into_nand(BOption<U>) -> BOption<()> Drops T, U
+//! into_nor(BOption<U>) -> BOption<()> Drops T, U
+//! into_xnor(BOption<U>) -> BOption<()> Drops T, U
+//! into_implies(BOption<U>) -> BOption<()> Drops T, U
+//!
+//! operators, with their assign variants:
+//! & BOption<U> same as into_map_flatten
+//! | BOption<T> same as into_collect
+//! ^ BOption<T> same as into_xor
+//! ! -> BOption<()> Drops T
+//!
 //! as_insert(T) -> &mut T ? Drops Arguments::T
 //! as_insert_or(T) -> &mut T ? Drops Self::T
 //! as_insert_or_else(|| T) &mut T
+//! as_insert_or_try_else(|| BResult<T, E>) -> BResult<&mut T, E> ?stays None if the closure fails or unwinds
+//! as_insert_or_try_else_boption(|| BOption<T>) -> BOption<&mut T> ?stays None if the closure fails or unwinds
 //! where T: Default
 //! as_insert_or_default() -> &mut T
 //!
 //! as_take() -> BOption<T>
 //! as_take_if(|&mut T| bool ? Drops T) BOption<T>
 //! as_replace(T) -> BOption<T>
+//! as_update(|T| BOption<T>) ?None if the closure unwinds
+//! as_map_in_place(|&mut T|)
+//! as_replace_with(|BOption<T>| BOption<T>) ?None if the closure unwinds
+//! as_swap_with(&mut BOption<T>)
+//! as_entry() -> BOptionEntry<T> Occupied or Vacant, see betterentry
 //!
 //! for<U>: zipping T with U into a tuple
 //! into_zip(U) -> BOption<(T, U)>
 //! into_unzip() -> (BOption<T>, BOption<U>)
 //! ```
+#[cfg(not(feature = "no-panic"))]
+use crate::betterhook::{UnwrapFailure, UnwrapVariant, call_unwrap_failure_hook};
+#[cfg(not(feature = "no-panic"))]
+use core::fmt;
+#[cfg(not(debug_assertions))]
 use core::hint::unreachable_unchecked;
 use core::mem::{self, ManuallyDrop};
 use core::mem::MaybeUninit;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Not};
+use core::pin::Pin;
+use core::slice;
 use core::option::Option;
+#[cfg(not(feature = "no-panic"))]
+use core::panic::Location;
 
 #[repr(C)]
 pub enum FfiOptionTag {
@@ -103,6 +162,21 @@ pub enum FfiOptionTag {
     None,
 }
 
+#[cfg(debug_assertions)]
+impl FfiOptionTag {
+    #[track_caller]
+    const fn debug_check(tag: *const FfiOptionTag, violation: &'static str) {
+        let raw = unsafe { tag.cast::<[u8; size_of::<FfiOptionTag>()]>().read() };
+        let valid = unsafe {
+            [
+                mem::transmute::<FfiOptionTag, [u8; size_of::<FfiOptionTag>()]>(FfiOptionTag::Some),
+                mem::transmute::<FfiOptionTag, [u8; size_of::<FfiOptionTag>()]>(FfiOptionTag::None),
+            ]
+        };
+        debug_check_ffi_tag(raw, valid, violation);
+    }
+}
+
 #[repr(C)]
 pub struct FfiOption<T> {
     tag: FfiOptionTag,
@@ -127,6 +201,8 @@ impl<T> FfiOption<T> {
 
 impl<T> Drop for FfiOption<T> {
     fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        FfiOptionTag::debug_check(&raw const self.tag, "FfiOption::drop found an invalid tag");
         match self.tag {
             FfiOptionTag::Some => {
                 unsafe { self.discr.assume_init_drop() }
@@ -137,8 +213,11 @@ impl<T> Drop for FfiOption<T> {
 }
 
 impl<T> FfiOption<T> {
+    #[cfg_attr(debug_assertions, track_caller)]
     pub fn into_boption(self) -> BOption<T> {
         let this = ManuallyDrop::new(self);
+        #[cfg(debug_assertions)]
+        FfiOptionTag::debug_check(&raw const this.tag, "FfiOption::into_boption found an invalid tag");
         match this.tag {
             FfiOptionTag::Some => {
                 let t = unsafe { this.discr.assume_init_read() };
@@ -202,6 +281,38 @@ impl<T> BOption<T> {
     pub const fn is_niche_optimized(&self) -> bool {
         size_of::<Self>() != size_of::<FfiOption<T>>()
     }
+    pub fn into_is_some_and(self, cond: impl FnOnce(T) -> bool) -> bool {
+        match self {
+            Some(t) => cond(t),
+            None => false,
+        }
+    }
+    pub fn into_is_none_or(self, cond: impl FnOnce(T) -> bool) -> bool {
+        match self {
+            Some(t) => cond(t),
+            None => true,
+        }
+    }
+    pub fn as_is_some_and(&self, cond: impl FnOnce(&T) -> bool) -> bool {
+        match *self {
+            Some(ref t) => cond(t),
+            None => false,
+        }
+    }
+    pub fn as_is_none_or(&self, cond: impl FnOnce(&T) -> bool) -> bool {
+        match *self {
+            Some(ref t) => cond(t),
+            None => true,
+        }
+    }
+    pub fn as_contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_is_some_and(|t| t == other)
+    }
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
     pub fn unwrap(self) -> T {
         match self {
             Some(t) => t,
@@ -229,12 +340,25 @@ impl<T> BOption<T> {
             None => T::default(),
         }
     }
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
     pub fn unwrap_none(self) {
         match self {
             Some(_) => unwrap_none_failed_default(),
             None => (),
         }
     }
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn unwrap_none_debug(self)
+    where
+        T: fmt::Debug,
+    {
+        match self {
+            Some(t) => unwrap_failed(UnwrapVariant::Some, format_args!("called unwrap_none on some value: {:?}", t)),
+            None => (),
+        }
+    }
     pub fn unwrap_none_or(self) {
         match self {
             Some(t) => drop(t),
@@ -253,15 +377,27 @@ impl<T> BOption<T> {
             None => <()>::default(),
         }
     }
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
     pub fn expect<S>(self, message: S) -> T
     where
         S: AsRef<str>,
     {
         match self {
             Some(t) => t,
-            None => unwrap_failed(message.as_ref()),
+            None => unwrap_failed(UnwrapVariant::None, format_args!("{}", message.as_ref())),
+        }
+    }
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn expect_with(self, message_lazy: impl Fn(&mut fmt::Formatter<'_>) -> fmt::Result) -> T {
+        match self {
+            Some(t) => t,
+            None => unwrap_failed(UnwrapVariant::None, format_args!("{}", FmtWith(message_lazy))),
         }
     }
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
     pub fn expect_none<S>(self, message: S)
     where
         S: AsRef<str>,
@@ -269,20 +405,45 @@ impl<T> BOption<T> {
         match self {
             Some(t) => {
                 drop(t);
-                unwrap_failed(message.as_ref())
+                unwrap_failed(UnwrapVariant::Some, format_args!("{}", message.as_ref()))
             }
             None => (),
         }
     }
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn expect_none_debug<S>(self, message: S)
+    where
+        S: AsRef<str>,
+        T: fmt::Debug,
+    {
+        match self {
+            Some(t) => unwrap_failed(UnwrapVariant::Some, format_args!("{}: {:?}", message.as_ref(), t)),
+            None => (),
+        }
+    }
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn expect_none_with(self, message_lazy: impl Fn(&mut fmt::Formatter<'_>) -> fmt::Result) {
+        match self {
+            Some(t) => {
+                drop(t);
+                unwrap_failed(UnwrapVariant::Some, format_args!("{}", FmtWith(message_lazy)))
+            }
+            None => (),
+        }
+    }
+    #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn unwrap_unchecked(self) -> T {
         match self {
             Some(t) => t,
-            None => unsafe { unreachable_unchecked() },
+            None => unsafe { debug_unreachable("BOption::unwrap_unchecked called on a None value") },
         }
     }
+    #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn unwrap_none_unchecked(self) {
         match self {
-            Some(_) => unsafe { unreachable_unchecked() },
+            Some(_) => unsafe { debug_unreachable("BOption::unwrap_none_unchecked called on a Some value") },
             None => (),
         }
     }
@@ -298,6 +459,48 @@ impl<T> BOption<T> {
             None => BOption::None,
         }
     }
+    pub fn as_deref(&self) -> BOption<&T::Target>
+    where
+        T: Deref,
+    {
+        match *self {
+            Some(ref t) => Some(t.deref()),
+            None => None,
+        }
+    }
+    pub fn as_deref_mut(&mut self) -> BOption<&mut T::Target>
+    where
+        T: DerefMut,
+    {
+        match *self {
+            Some(ref mut t) => Some(t.deref_mut()),
+            None => None,
+        }
+    }
+    pub fn as_pin_ref(self: Pin<&Self>) -> BOption<Pin<&T>> {
+        match *Pin::get_ref(self) {
+            Some(ref t) => Some(unsafe { Pin::new_unchecked(t) }),
+            None => None,
+        }
+    }
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> BOption<Pin<&mut T>> {
+        match *unsafe { Pin::get_unchecked_mut(self) } {
+            Some(ref mut t) => Some(unsafe { Pin::new_unchecked(t) }),
+            None => None,
+        }
+    }
+    pub fn as_slice(&self) -> &[T] {
+        match *self {
+            Some(ref t) => slice::from_ref(t),
+            None => &[],
+        }
+    }
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match *self {
+            Some(ref mut t) => slice::from_mut(t),
+            None => &mut [],
+        }
+    }
     pub fn into_option(self) -> Option<T> {
         match self {
             Some(t) => Option::Some(t),
@@ -434,6 +637,30 @@ impl<T> BOption<T> {
             _ => None,
         }
     }
+    pub fn into_nand<U>(self, other: BOption<U>) -> BOption<()> {
+        match (self, other) {
+            (Some(_), Some(_)) => None,
+            _ => Some(()),
+        }
+    }
+    pub fn into_nor<U>(self, other: BOption<U>) -> BOption<()> {
+        match (self, other) {
+            (None, None) => Some(()),
+            _ => None,
+        }
+    }
+    pub fn into_xnor<U>(self, other: BOption<U>) -> BOption<()> {
+        match (self, other) {
+            (Some(_), Some(_)) | (None, None) => Some(()),
+            _ => None,
+        }
+    }
+    pub fn into_implies<U>(self, other: BOption<U>) -> BOption<()> {
+        match (self, other) {
+            (Some(_), None) => None,
+            _ => Some(()),
+        }
+    }
     pub fn as_insert(&mut self, default: T) -> &mut T {
         match *self {
             Some(ref mut t) => t,
@@ -459,6 +686,30 @@ impl<T> BOption<T> {
             }
         }
     }
+    pub fn as_insert_or_try_else<E>(&mut self, other_lazy: impl FnOnce() -> BResult<T, E>) -> BResult<&mut T, E> {
+        match *self {
+            Some(ref mut t) => Ok(t),
+            None => match other_lazy() {
+                Ok(t) => {
+                    mem::forget(mem::replace(self, Some(t)));
+                    Ok(unsafe { self.as_mut().unwrap_unchecked() })
+                }
+                Err(e) => Err(e),
+            },
+        }
+    }
+    pub fn as_insert_or_try_else_boption(&mut self, other_lazy: impl FnOnce() -> BOption<T>) -> BOption<&mut T> {
+        match *self {
+            Some(ref mut t) => Some(t),
+            None => match other_lazy() {
+                Some(t) => {
+                    mem::forget(mem::replace(self, Some(t)));
+                    Some(unsafe { self.as_mut().unwrap_unchecked() })
+                }
+                None => None,
+            },
+        }
+    }
     pub fn as_insert_or_default(&mut self) -> &mut T
     where
         T: Default,
@@ -489,6 +740,21 @@ impl<T> BOption<T> {
     pub fn as_replace(&mut self, replacer: T) -> BOption<T> {
         mem::replace(self, Some(replacer))
     }
+    pub fn as_update(&mut self, updater: impl FnOnce(T) -> BOption<T>) {
+        *self = self.as_take().into_map_flatten_lazy(updater);
+    }
+    pub fn as_map_in_place(&mut self, mapper: impl FnOnce(&mut T)) {
+        match *self {
+            Some(ref mut t) => mapper(t),
+            None => {}
+        }
+    }
+    pub fn as_replace_with(&mut self, replacer: impl FnOnce(BOption<T>) -> BOption<T>) {
+        *self = replacer(self.as_take());
+    }
+    pub fn as_swap_with(&mut self, other: &mut BOption<T>) {
+        mem::swap(self, other);
+    }
     pub fn into_zip<U>(self, other: U) -> BOption<(T, U)> {
         match self {
             Some(t) => Some((t, other)),
@@ -568,20 +834,141 @@ impl<T: Copy> BOption<&T> {
     }
 }
 
+impl<T: Clone> BOption<&mut T> {
+    pub fn into_cloned(self) -> BOption<T> {
+        match self {
+            Some(t) => Some(t.clone()),
+            None => None,
+        }
+    }
+}
+
+impl<T: Copy> BOption<&mut T> {
+    pub fn into_copied(self) -> BOption<T> {
+        match self {
+            Some(t) => Some(*t),
+            None => None,
+        }
+    }
+}
+
+impl<T, U> BitAnd<BOption<U>> for BOption<T> {
+    type Output = BOption<U>;
+
+    fn bitand(self, other: BOption<U>) -> BOption<U> {
+        self.into_map_flatten(other)
+    }
+}
+
+impl<T> BitAndAssign for BOption<T> {
+    fn bitand_assign(&mut self, other: BOption<T>) {
+        *self = self.as_take().into_map_flatten(other);
+    }
+}
+
+impl<T> BitOr for BOption<T> {
+    type Output = BOption<T>;
+
+    fn bitor(self, other: BOption<T>) -> BOption<T> {
+        self.into_collect(other)
+    }
+}
+
+impl<T> BitOrAssign for BOption<T> {
+    fn bitor_assign(&mut self, other: BOption<T>) {
+        *self = self.as_take().into_collect(other);
+    }
+}
+
+impl<T> BitXor for BOption<T> {
+    type Output = BOption<T>;
+
+    fn bitxor(self, other: BOption<T>) -> BOption<T> {
+        self.into_xor(other)
+    }
+}
+
+impl<T> BitXorAssign for BOption<T> {
+    fn bitxor_assign(&mut self, other: BOption<T>) {
+        *self = self.as_take().into_xor(other);
+    }
+}
+
+impl<T> Not for BOption<T> {
+    type Output = BOption<()>;
+
+    fn not(self) -> BOption<()> {
+        match self {
+            Some(_) => None,
+            None => Some(()),
+        }
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+pub(crate) struct FmtWith<F>(pub(crate) F)
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+#[cfg(not(feature = "no-panic"))]
+impl<F> fmt::Display for FmtWith<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
 #[track_caller]
 #[inline(always)]
 fn unwrap_failed_default() -> ! {
-    unwrap_failed("called unwrap on none value")
+    unwrap_failed(UnwrapVariant::None, format_args!("called unwrap on none value"))
 }
 
+#[cfg(not(feature = "no-panic"))]
 #[track_caller]
 #[inline(always)]
 fn unwrap_none_failed_default() -> ! {
-    unwrap_failed("called unwrap_none on some value")
+    unwrap_failed(UnwrapVariant::Some, format_args!("called unwrap_none on some value"))
 }
 
+#[cfg(not(feature = "no-panic"))]
 #[track_caller]
 #[inline(never)]
-fn unwrap_failed(message: &str) -> ! {
+fn unwrap_failed(found: UnwrapVariant, message: fmt::Arguments<'_>) -> ! {
+    call_unwrap_failure_hook(&UnwrapFailure::new(message, Location::caller(), found));
     panic!("unwrap failed: {}", message);
 }
+
+#[cfg_attr(debug_assertions, track_caller)]
+#[inline(always)]
+pub(crate) const unsafe fn debug_unreachable(violation: &'static str) -> ! {
+    #[cfg(debug_assertions)]
+    {
+        panic!("{}", violation)
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        let _ = violation;
+        unsafe { unreachable_unchecked() }
+    }
+}
+
+#[cfg(debug_assertions)]
+#[track_caller]
+pub(crate) const fn debug_check_ffi_tag<const N: usize>(raw: [u8; N], valid: [[u8; N]; 2], violation: &'static str) {
+    let mut candidate = 0;
+    while candidate < valid.len() {
+        let mut byte = 0;
+        while byte < N && raw[byte] == valid[candidate][byte] {
+            byte += 1;
+        }
+        if byte == N {
+            return;
+        }
+        candidate += 1;
+    }
+    panic!("{}", violation)
+}
@@ -0,0 +1,254 @@
+//! ```ignore
+//! BOutcome<T, R, F>: Success(T), Recoverable(R) or Fatal(F)
+//!
+//! is_success()         -> bool
+//! is_not_success()     -> bool
+//! is_recoverable()     -> bool
+//! is_not_recoverable() -> bool
+//! is_fatal()           -> bool
+//! is_not_fatal()       -> bool
+//!
+//! as_ref() -> BOutcome<&T, &R, &F>
+//! as_mut() -> BOutcome<&mut T, &mut R, &mut F>
+//!
+//! into_boption_success    () -> BOption<T>
+//! into_boption_recoverable() -> BOption<R>
+//! into_boption_fatal      () -> BOption<F>
+//!
+//! into_map_success    (|T| U ?Drops T) -> BOutcome<U, R, F>
+//! into_map_recoverable(|R| S ?Drops R) -> BOutcome<T, S, F>
+//! into_map_fatal      (|F| G ?Drops F) -> BOutcome<T, R, G>
+//!
+//! into_map_success_flatten_lazy    (|T| BOutcome<U, R, F>) -> BOutcome<U, R, F> stops on Recoverable and Fatal
+//! into_map_recoverable_flatten_lazy(|R| BOutcome<T, S, F>) -> BOutcome<T, S, F> e.g. retrying, stops on Fatal
+//!
+//! fold_until_fatal(IntoIterator<BOutcome<T, R, F>>, A, |A, T| A, |A, R| A) -> BResult<A, F>
+//!     folds successes and recoverable failures alike, stops at the first Fatal
+//!
+//! escalation, Recoverable becomes an error:
+//! from_bresult_fatal(BResult<T, F>) -> BOutcome<T, R, F>
+//! into_escalated(|R| F) -> BResult<T, F>
+//!
+//! de-escalation, only Fatal is an error:
+//! from_bresult_recoverable(BResult<T, R>) -> BOutcome<T, R, F>
+//! from_bresult_deescalated(BResult<BResult<T, R>, F>) -> BOutcome<T, R, F>
+//! into_bresult_deescalated() -> BResult<BResult<T, R>, F>
+//! into_bresult_skipping() -> BResult<BOption<T>, F> Drops R
+//!
+//! from_bresult_split(BResult<T, BResult<R, F>>) -> BOutcome<T, R, F>
+//! into_bresult_split() -> BResult<T, BResult<R, F>>
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+
+pub enum BOutcome<T, R, F> {
+    Success(T),
+    Recoverable(R),
+    Fatal(F),
+}
+use BOutcome::{Fatal, Recoverable, Success};
+
+impl<T, R, F> BOutcome<T, R, F> {
+    pub const fn is_success(&self) -> bool {
+        match *self {
+            Success(_) => true,
+            Recoverable(_) | Fatal(_) => false,
+        }
+    }
+
+    pub const fn is_not_success(&self) -> bool {
+        match *self {
+            Success(_) => false,
+            Recoverable(_) | Fatal(_) => true,
+        }
+    }
+
+    pub const fn is_recoverable(&self) -> bool {
+        match *self {
+            Recoverable(_) => true,
+            Success(_) | Fatal(_) => false,
+        }
+    }
+
+    pub const fn is_not_recoverable(&self) -> bool {
+        match *self {
+            Recoverable(_) => false,
+            Success(_) | Fatal(_) => true,
+        }
+    }
+
+    pub const fn is_fatal(&self) -> bool {
+        match *self {
+            Fatal(_) => true,
+            Success(_) | Recoverable(_) => false,
+        }
+    }
+
+    pub const fn is_not_fatal(&self) -> bool {
+        match *self {
+            Fatal(_) => false,
+            Success(_) | Recoverable(_) => true,
+        }
+    }
+
+    pub const fn as_ref(&self) -> BOutcome<&T, &R, &F> {
+        match *self {
+            Success(ref t) => Success(t),
+            Recoverable(ref r) => Recoverable(r),
+            Fatal(ref f) => Fatal(f),
+        }
+    }
+
+    pub const fn as_mut(&mut self) -> BOutcome<&mut T, &mut R, &mut F> {
+        match *self {
+            Success(ref mut t) => Success(t),
+            Recoverable(ref mut r) => Recoverable(r),
+            Fatal(ref mut f) => Fatal(f),
+        }
+    }
+
+    pub fn into_boption_success(self) -> BOption<T> {
+        match self {
+            Success(t) => Some(t),
+            Recoverable(_) | Fatal(_) => None,
+        }
+    }
+
+    pub fn into_boption_recoverable(self) -> BOption<R> {
+        match self {
+            Recoverable(r) => Some(r),
+            Success(_) | Fatal(_) => None,
+        }
+    }
+
+    pub fn into_boption_fatal(self) -> BOption<F> {
+        match self {
+            Fatal(f) => Some(f),
+            Success(_) | Recoverable(_) => None,
+        }
+    }
+
+    pub fn into_map_success<U>(self, mapper: impl FnOnce(T) -> U) -> BOutcome<U, R, F> {
+        match self {
+            Success(t) => Success(mapper(t)),
+            Recoverable(r) => Recoverable(r),
+            Fatal(f) => Fatal(f),
+        }
+    }
+
+    pub fn into_map_recoverable<S>(self, mapper_recoverable: impl FnOnce(R) -> S) -> BOutcome<T, S, F> {
+        match self {
+            Success(t) => Success(t),
+            Recoverable(r) => Recoverable(mapper_recoverable(r)),
+            Fatal(f) => Fatal(f),
+        }
+    }
+
+    pub fn into_map_fatal<G>(self, mapper_fatal: impl FnOnce(F) -> G) -> BOutcome<T, R, G> {
+        match self {
+            Success(t) => Success(t),
+            Recoverable(r) => Recoverable(r),
+            Fatal(f) => Fatal(mapper_fatal(f)),
+        }
+    }
+
+    pub fn into_map_success_flatten_lazy<U>(self, other_if_success_lazy: impl FnOnce(T) -> BOutcome<U, R, F>) -> BOutcome<U, R, F> {
+        match self {
+            Success(t) => other_if_success_lazy(t),
+            Recoverable(r) => Recoverable(r),
+            Fatal(f) => Fatal(f),
+        }
+    }
+
+    pub fn into_map_recoverable_flatten_lazy<S>(
+        self,
+        other_if_recoverable_lazy: impl FnOnce(R) -> BOutcome<T, S, F>,
+    ) -> BOutcome<T, S, F> {
+        match self {
+            Success(t) => Success(t),
+            Recoverable(r) => other_if_recoverable_lazy(r),
+            Fatal(f) => Fatal(f),
+        }
+    }
+
+    pub fn fold_until_fatal<A>(
+        outcomes: impl IntoIterator<Item = BOutcome<T, R, F>>,
+        init: A,
+        mut on_success: impl FnMut(A, T) -> A,
+        mut on_recoverable: impl FnMut(A, R) -> A,
+    ) -> BResult<A, F> {
+        let mut acc = init;
+        for outcome in outcomes {
+            acc = match outcome {
+                Success(t) => on_success(acc, t),
+                Recoverable(r) => on_recoverable(acc, r),
+                Fatal(f) => return Err(f),
+            };
+        }
+        Ok(acc)
+    }
+
+    pub fn from_bresult_fatal(result: BResult<T, F>) -> Self {
+        match result {
+            Ok(t) => Success(t),
+            Err(f) => Fatal(f),
+        }
+    }
+
+    pub fn into_escalated(self, escalate: impl FnOnce(R) -> F) -> BResult<T, F> {
+        match self {
+            Success(t) => Ok(t),
+            Recoverable(r) => Err(escalate(r)),
+            Fatal(f) => Err(f),
+        }
+    }
+
+    pub fn from_bresult_recoverable(result: BResult<T, R>) -> Self {
+        match result {
+            Ok(t) => Success(t),
+            Err(r) => Recoverable(r),
+        }
+    }
+
+    pub fn from_bresult_deescalated(result: BResult<BResult<T, R>, F>) -> Self {
+        match result {
+            Ok(Ok(t)) => Success(t),
+            Ok(Err(r)) => Recoverable(r),
+            Err(f) => Fatal(f),
+        }
+    }
+
+    pub fn into_bresult_deescalated(self) -> BResult<BResult<T, R>, F> {
+        match self {
+            Success(t) => Ok(Ok(t)),
+            Recoverable(r) => Ok(Err(r)),
+            Fatal(f) => Err(f),
+        }
+    }
+
+    pub fn into_bresult_skipping(self) -> BResult<BOption<T>, F> {
+        match self {
+            Success(t) => Ok(Some(t)),
+            Recoverable(_) => Ok(None),
+            Fatal(f) => Err(f),
+        }
+    }
+
+    pub fn from_bresult_split(result: BResult<T, BResult<R, F>>) -> Self {
+        match result {
+            Ok(t) => Success(t),
+            Err(Ok(r)) => Recoverable(r),
+            Err(Err(f)) => Fatal(f),
+        }
+    }
+
+    pub fn into_bresult_split(self) -> BResult<T, BResult<R, F>> {
+        match self {
+            Success(t) => Ok(t),
+            Recoverable(r) => Err(Ok(r)),
+            Fatal(f) => Err(Err(f)),
+        }
+    }
+}
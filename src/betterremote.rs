@@ -0,0 +1,223 @@
+//! ```ignore
+//! BRemote<T, E>: NotRequested, Loading, Loaded(T) or Failed(E), for values fetched from elsewhere
+//!
+//! is_requested()     -> bool anything but NotRequested
+//! is_not_requested() -> bool
+//! is_loading()       -> bool
+//! is_not_loading()   -> bool
+//! is_loaded()        -> bool
+//! is_not_loaded()    -> bool
+//! is_failed()        -> bool
+//! is_not_failed()    -> bool
+//! is_settled()       -> bool Loaded or Failed
+//! is_not_settled()   -> bool
+//!
+//! transitions, returning the previous state:
+//! as_begin_loading() -> BRemote<T, E>
+//! as_resolve(BResult<T, E>) -> BRemote<T, E>
+//! as_reset() -> BRemote<T, E> back to NotRequested
+//!
+//! as_ref() -> BRemote<&T, &E>
+//! as_mut() -> BRemote<&mut T, &mut E>
+//!
+//! for <U>: mapping T into U
+//! into_map_loaded(|T| U ?Drops T) -> BRemote<U, E>
+//! into_map_loaded_flatten_lazy(|T| BRemote<U, E> ?Drops T) -> BRemote<U, E>
+//! for <F>: mapping E into F
+//! into_map_failed(|E| F ?Drops E) -> BRemote<T, F>
+//!
+//! from_bresult(BResult<T, E>) -> BRemote<T, E> Loaded or Failed
+//! from_boption_bresult(BOption<BResult<T, E>>, bool) -> BRemote<T, E> None is Loading if the flag is set, NotRequested otherwise
+//!
+//! into_boption_loaded() -> BOption<T> Drops E, NotRequested and Loading are None
+//! into_boption_failed() -> BOption<E> Drops T, NotRequested and Loading are None
+//! into_settled() -> BOption<BResult<T, E>> NotRequested and Loading are None
+//! into_bresult_or_else(|| E) -> BResult<T, E> NotRequested and Loading become the lazy error
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use core::mem;
+
+pub enum BRemote<T, E> {
+    NotRequested,
+    Loading,
+    Loaded(T),
+    Failed(E),
+}
+use BRemote::{Failed, Loaded, Loading, NotRequested};
+
+impl<T, E> BRemote<T, E> {
+    pub const fn is_requested(&self) -> bool {
+        match *self {
+            NotRequested => false,
+            Loading | Loaded(_) | Failed(_) => true,
+        }
+    }
+
+    pub const fn is_not_requested(&self) -> bool {
+        match *self {
+            NotRequested => true,
+            Loading | Loaded(_) | Failed(_) => false,
+        }
+    }
+
+    pub const fn is_loading(&self) -> bool {
+        match *self {
+            Loading => true,
+            NotRequested | Loaded(_) | Failed(_) => false,
+        }
+    }
+
+    pub const fn is_not_loading(&self) -> bool {
+        match *self {
+            Loading => false,
+            NotRequested | Loaded(_) | Failed(_) => true,
+        }
+    }
+
+    pub const fn is_loaded(&self) -> bool {
+        match *self {
+            Loaded(_) => true,
+            NotRequested | Loading | Failed(_) => false,
+        }
+    }
+
+    pub const fn is_not_loaded(&self) -> bool {
+        match *self {
+            Loaded(_) => false,
+            NotRequested | Loading | Failed(_) => true,
+        }
+    }
+
+    pub const fn is_failed(&self) -> bool {
+        match *self {
+            Failed(_) => true,
+            NotRequested | Loading | Loaded(_) => false,
+        }
+    }
+
+    pub const fn is_not_failed(&self) -> bool {
+        match *self {
+            Failed(_) => false,
+            NotRequested | Loading | Loaded(_) => true,
+        }
+    }
+
+    pub const fn is_settled(&self) -> bool {
+        match *self {
+            Loaded(_) | Failed(_) => true,
+            NotRequested | Loading => false,
+        }
+    }
+
+    pub const fn is_not_settled(&self) -> bool {
+        match *self {
+            Loaded(_) | Failed(_) => false,
+            NotRequested | Loading => true,
+        }
+    }
+
+    pub const fn as_begin_loading(&mut self) -> BRemote<T, E> {
+        mem::replace(self, Loading)
+    }
+
+    pub fn as_resolve(&mut self, result: BResult<T, E>) -> BRemote<T, E> {
+        mem::replace(self, BRemote::from_bresult(result))
+    }
+
+    pub const fn as_reset(&mut self) -> BRemote<T, E> {
+        mem::replace(self, NotRequested)
+    }
+
+    pub const fn as_ref(&self) -> BRemote<&T, &E> {
+        match *self {
+            NotRequested => NotRequested,
+            Loading => Loading,
+            Loaded(ref t) => Loaded(t),
+            Failed(ref e) => Failed(e),
+        }
+    }
+
+    pub const fn as_mut(&mut self) -> BRemote<&mut T, &mut E> {
+        match *self {
+            NotRequested => NotRequested,
+            Loading => Loading,
+            Loaded(ref mut t) => Loaded(t),
+            Failed(ref mut e) => Failed(e),
+        }
+    }
+
+    pub fn into_map_loaded<U>(self, mapper: impl FnOnce(T) -> U) -> BRemote<U, E> {
+        match self {
+            NotRequested => NotRequested,
+            Loading => Loading,
+            Loaded(t) => Loaded(mapper(t)),
+            Failed(e) => Failed(e),
+        }
+    }
+
+    pub fn into_map_loaded_flatten_lazy<U>(self, other_if_loaded_lazy: impl FnOnce(T) -> BRemote<U, E>) -> BRemote<U, E> {
+        match self {
+            NotRequested => NotRequested,
+            Loading => Loading,
+            Loaded(t) => other_if_loaded_lazy(t),
+            Failed(e) => Failed(e),
+        }
+    }
+
+    pub fn into_map_failed<F>(self, mapper_failed: impl FnOnce(E) -> F) -> BRemote<T, F> {
+        match self {
+            NotRequested => NotRequested,
+            Loading => Loading,
+            Loaded(t) => Loaded(t),
+            Failed(e) => Failed(mapper_failed(e)),
+        }
+    }
+
+    pub fn from_bresult(result: BResult<T, E>) -> Self {
+        match result {
+            Ok(t) => Loaded(t),
+            Err(e) => Failed(e),
+        }
+    }
+
+    pub fn from_boption_bresult(settled: BOption<BResult<T, E>>, loading: bool) -> Self {
+        match settled {
+            Some(result) => BRemote::from_bresult(result),
+            None if loading => Loading,
+            None => NotRequested,
+        }
+    }
+
+    pub fn into_boption_loaded(self) -> BOption<T> {
+        match self {
+            Loaded(t) => Some(t),
+            NotRequested | Loading | Failed(_) => None,
+        }
+    }
+
+    pub fn into_boption_failed(self) -> BOption<E> {
+        match self {
+            Failed(e) => Some(e),
+            NotRequested | Loading | Loaded(_) => None,
+        }
+    }
+
+    pub fn into_settled(self) -> BOption<BResult<T, E>> {
+        match self {
+            NotRequested | Loading => None,
+            Loaded(t) => Some(Ok(t)),
+            Failed(e) => Some(Err(e)),
+        }
+    }
+
+    pub fn into_bresult_or_else(self, not_settled_lazy: impl FnOnce() -> E) -> BResult<T, E> {
+        match self {
+            NotRequested | Loading => Err(not_settled_lazy()),
+            Loaded(t) => Ok(t),
+            Failed(e) => Err(e),
+        }
+    }
+}
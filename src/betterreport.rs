@@ -0,0 +1,141 @@
+//! ```ignore
+//! BResult<T, E> where E: Error
+//!
+//! as_err_chain() -> ErrChain (E, E::source(), ...) empty if Ok
+//! into_err_report() -> BResult<T, ErrReport<E>>
+//!
+//! ErrReport<E>
+//!
+//! new(E) -> ErrReport<E> multi-line by default
+//! into_single_line() -> ErrReport<E>
+//! into_multi_line() -> ErrReport<E>
+//! is_multi_line() -> bool
+//! as_error() -> &E
+//! into_error() -> E
+//! as_chain() -> ErrChain
+//!
+//! single-line: `top: first source: second source`
+//! multi-line:
+//!     top
+//!
+//!     caused by:
+//!         1: first source
+//!         2: second source
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use core::error::Error;
+use core::fmt;
+
+pub struct ErrChain<'a> {
+    next: BOption<&'a (dyn Error + 'a)>,
+}
+
+impl<'a> ErrChain<'a> {
+    pub fn new(error: &'a (dyn Error + 'a)) -> Self {
+        ErrChain { next: Some(error) }
+    }
+
+    pub const fn new_empty() -> Self {
+        ErrChain { next: None }
+    }
+}
+
+impl<'a> Iterator for ErrChain<'a> {
+    type Item = &'a (dyn Error + 'a);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next.as_take() {
+            Some(current) => {
+                self.next = match current.source() {
+                    Option::Some(source) => Some(source),
+                    Option::None => None,
+                };
+                Option::Some(current)
+            }
+            None => Option::None,
+        }
+    }
+}
+
+pub struct ErrReport<E> {
+    error: E,
+    multi_line: bool,
+}
+
+impl<E: Error> ErrReport<E> {
+    pub fn new(error: E) -> Self {
+        ErrReport { error, multi_line: true }
+    }
+
+    pub fn into_single_line(self) -> Self {
+        ErrReport {
+            error: self.error,
+            multi_line: false,
+        }
+    }
+
+    pub fn into_multi_line(self) -> Self {
+        ErrReport {
+            error: self.error,
+            multi_line: true,
+        }
+    }
+
+    pub fn is_multi_line(&self) -> bool {
+        self.multi_line
+    }
+
+    pub fn as_error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    pub fn as_chain(&self) -> ErrChain<'_> {
+        ErrChain::new(&self.error)
+    }
+}
+
+impl<E: Error> fmt::Display for ErrReport<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        let sources = self.as_chain().skip(1);
+        if self.multi_line {
+            for (index, source) in sources.enumerate() {
+                if index == 0 {
+                    f.write_str("\n\ncaused by:")?;
+                }
+                write!(f, "\n    {}: {}", index + 1, source)?;
+            }
+        } else {
+            for source in sources {
+                write!(f, ": {}", source)?;
+            }
+        }
+        Result::Ok(())
+    }
+}
+
+impl<E: Error> fmt::Debug for ErrReport<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T, E: Error> BResult<T, E> {
+    pub fn as_err_chain(&self) -> ErrChain<'_> {
+        match *self {
+            Ok(_) => ErrChain::new_empty(),
+            Err(ref e) => ErrChain::new(e),
+        }
+    }
+
+    pub fn into_err_report(self) -> BResult<T, ErrReport<E>> {
+        self.into_map_err(ErrReport::new)
+    }
+}
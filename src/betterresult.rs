@@ -2,7 +2,9 @@
 //! ```ignore
 //! BResult<T, E>
 //!
-//! unwrap()                      -> T ?panic
+//! unwrap()                      -> T ?panic without E, see unwrap_debug
+//! where E: Debug
+//! unwrap_debug()                -> T ?panic with E's Debug
 //! unwrap_or(T)                  -> T ?Drops E
 //! unwrap_or_else(|E| T ?Drops E) -> T
 //! where T: Default
@@ -13,7 +15,9 @@
 //! where T = Infallible | !
 //! into_err_infallible() -> E
 //!
-//! unwrap_err()                      -> E ?panic
+//! unwrap_err()                      -> E ?panic without T, see unwrap_err_debug
+//! where T: Debug
+//! unwrap_err_debug()                -> E ?panic with T's Debug
 //! unwrap_err_or(E)                  -> E ?Drops T
 //! unwrap_err_or_else(|T| E ?Drops T) -> E
 //! where E: Default
@@ -22,9 +26,23 @@
 //! where S: AsRef<str>
 //! expect(S)     -> T ?Drops E + panic
 //! expect_err(S) -> E ?Drops T + panic
+//! where S: AsRef<str>, E: Debug
+//! expect_debug(S) -> T ?panic with E's Debug
+//! where S: AsRef<str>, T: Debug
+//! expect_err_debug(S) -> E ?panic with T's Debug
+//!
+//! expect_with(|&mut Formatter| fmt::Result)     -> T ?Drops E + panic, message formatted only on failure
+//! expect_err_with(|&mut Formatter| fmt::Result) -> E ?Drops T + panic, message formatted only on failure
+//! see betterhook for how to call the *_with variants and why plain unwrap/expect omit the payload
+//!
+//! every panicking method reports the caller's location
+//! and is compiled out by the `no-panic` feature
 //!
 //! unsafe unwrap_unchecked()     -> T ?ub
 //! unsafe unwrap_err_unchecked() -> E ?ub
+//! ?ub panics with the method name and caller location when debug assertions are on,
+//! FfiResult's tag is checked the same way before it is read,
+//! both stay in debug builds even with `no-panic`, release builds never check
 //!
 //! is_ok()      -> bool
 //! is_not_ok()  -> bool
@@ -39,6 +57,17 @@
 //! into_is_err_and(|E| bool ?Drops E) -> bool ?Drops T
 //! into_is_err_or (|T| bool ?Drops T) -> bool ?Drops E
 //!
+//! as_is_ok_and(|&T| bool) -> bool
+//! as_is_ok_or (|&E| bool) -> bool
+//!
+//! as_is_err_and(|&E| bool) -> bool
+//! as_is_err_or (|&T| bool) -> bool
+//!
+//! where T: PartialEq
+//! as_contains(&T) -> bool
+//! where E: PartialEq
+//! as_contains_err(&E) -> bool
+//!
 //! into_boption    () -> BOption<T> Drops E
 //! into_boption_err() -> BOption<E> Drops T
 //!
@@ -53,6 +82,12 @@
 //!
 //! as_ref() -> BResult<&T, &E>
 //! as_mut() -> BResult<&mut T, &mut E>
+//! where T: Deref
+//! as_deref() -> BResult<&T::Target, &E>
+//! where T: DerefMut
+//! as_deref_mut() -> BResult<&mut T::Target, &mut E>
+//! as_pin_ref(self: Pin<&Self>) -> BResult<Pin<&T>, Pin<&E>>
+//! as_pin_mut(self: Pin<&mut Self>) -> BResult<Pin<&mut T>, Pin<&mut E>>
 //!
 //! where T: Clone
 //! into_cloned() -> BResult<T, E>
@@ -83,6 +118,14 @@
 //! as_inspect    (|&T|)
 //! as_inspect_err(|&E|)
 //!
+//! as_replace_ok (T) -> BResult<T, E>
+//! as_replace_err(E) -> BResult<T, E>
+//! as_map_ok_in_place (|&mut T|)
+//! as_map_err_in_place(|&mut E|)
+//! as_recover(|E| T) -> &mut T ?aborts if the closure unwinds, compiled out by the `no-panic` feature
+//! as_recover_ref(|&E| T) ?Drops E once T exists, unchanged if the closure unwinds
+//! as_swap_with(&mut BResult<T, E>)
+//!
 //! for <U>: mapping T into BResult<U, E>
 //! into_map_ok_flatten     (    BResult<U, E>         ) -> BResult<U, E> ?Drops T
 //! into_map_ok_flatten_lazy(|T| BResult<U, E> ?Drops T) -> BResult<U, E>
@@ -90,6 +133,15 @@
 //! into_map_err_flatten     (    BResult<T, F>         ) -> BResult<T, F>
 //! into_map_err_flatten_lazy(|E| BResult<T, F> ?Drops E) -> BResult<T, F>
 //!
+//! for <U, F>: true is Ok
+//! into_nand(BResult<U, F>) -> BResult<(), ()> Drops T, E, U, F
+//! into_nor(BResult<U, F>) -> BResult<(), ()> Drops T, E, U, F
+//! into_xnor(BResult<U, F>) -> BResult<(), ()> Drops T, E, U, F
+//! into_implies(BResult<U, F>) -> BResult<(), ()> Drops T, E, U, F
+//!
+//! into_control_flow() -> ControlFlow<E, T> Ok is Continue, Err is Break
+//! from_control_flow(ControlFlow<E, T>) -> BResult<T, E>
+//!
 //! into_result(Result<T, E>)
 //! into_ffi_result(FfiResult<T, E>)
 //!
@@ -103,10 +155,25 @@
 //! ```
 use crate::betteroption::BOption;
 use crate::betteroption::BOption::{None, Some};
+use crate::betteroption::debug_unreachable;
+#[cfg(debug_assertions)]
+use crate::betteroption::debug_check_ffi_tag;
+#[cfg(not(feature = "no-panic"))]
+use crate::betteroption::FmtWith;
+#[cfg(not(feature = "no-panic"))]
+use crate::betterhook::{UnwrapFailure, UnwrapVariant, call_unwrap_failure_hook};
 use core::convert::Infallible;
-use core::hint::unreachable_unchecked;
+#[cfg(not(feature = "no-panic"))]
+use core::fmt;
+use core::mem;
 use core::mem::ManuallyDrop;
 use core::mem::size_of;
+use core::ops::{ControlFlow, Deref, DerefMut};
+use core::pin::Pin;
+#[cfg(not(feature = "no-panic"))]
+use core::panic::Location;
+#[cfg(not(feature = "no-panic"))]
+use core::ptr;
 use core::result::Result;
 
 #[allow(non_snake_case)]
@@ -122,6 +189,21 @@ pub enum FfiResultTag {
     Err = 1,
 }
 
+#[cfg(debug_assertions)]
+impl FfiResultTag {
+    #[track_caller]
+    const fn debug_check(tag: *const FfiResultTag, violation: &'static str) {
+        let raw = unsafe { tag.cast::<[u8; size_of::<FfiResultTag>()]>().read() };
+        let valid = unsafe {
+            [
+                mem::transmute::<FfiResultTag, [u8; size_of::<FfiResultTag>()]>(FfiResultTag::Ok),
+                mem::transmute::<FfiResultTag, [u8; size_of::<FfiResultTag>()]>(FfiResultTag::Err),
+            ]
+        };
+        debug_check_ffi_tag(raw, valid, violation);
+    }
+}
+
 #[repr(C)]
 pub struct FfiResult<T, E> {
     tag: FfiResultTag,
@@ -146,6 +228,8 @@ impl<T, E> FfiResult<T, E> {
 
 impl<T, E> Drop for FfiResult<T, E> {
     fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        FfiResultTag::debug_check(&raw const self.tag, "FfiResult::drop found an invalid tag");
         match self.tag {
             FfiResultTag::Ok => {
                 let field = unsafe { &mut self.discriminant.Ok };
@@ -185,8 +269,14 @@ const fn manually_drop_as_ptr<T>(md: &ManuallyDrop<T>) -> *const T {
 }
 
 impl<T, E> FfiResult<T, E> {
+    #[cfg_attr(debug_assertions, track_caller)]
     pub const fn into_bresult(self) -> BResult<T, E> {
         let this = ManuallyDrop::new(self);
+        #[cfg(debug_assertions)]
+        FfiResultTag::debug_check(
+            unsafe { &raw const (*manually_drop_as_ptr(&this)).tag },
+            "FfiResult::into_bresult found an invalid tag",
+        );
         match unsafe { (&raw const (*manually_drop_as_ptr(&this)).tag).read() } {
             FfiResultTag::Ok => {
                 let field_ptr = unsafe { &raw const (*manually_drop_as_ptr(&this)).discriminant.Ok };
@@ -203,6 +293,8 @@ impl<T, E> FfiResult<T, E> {
 }
 
 impl<T, E> BResult<T, E> {
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
     pub fn unwrap(self) -> T {
         match self {
             Ok(t) => t,
@@ -210,6 +302,18 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn unwrap_debug(self) -> T
+    where
+        E: fmt::Debug,
+    {
+        match self {
+            Ok(t) => t,
+            Err(e) => unwrap_failed(UnwrapVariant::Err, format_args!("called unwrap on Err value: {:?}", e)),
+        }
+    }
+
     pub fn unwrap_or(self, default_eager: T) -> T {
         match self {
             Ok(t) => t,
@@ -234,6 +338,8 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
     pub fn unwrap_err(self) -> E {
         match self {
             Ok(_) => unwrap_err_failed_default(),
@@ -241,6 +347,18 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn unwrap_err_debug(self) -> E
+    where
+        T: fmt::Debug,
+    {
+        match self {
+            Ok(t) => unwrap_failed(UnwrapVariant::Ok, format_args!("called unwrap_err on Ok value: {:?}", t)),
+            Err(e) => e,
+        }
+    }
+
     pub fn unwrap_err_or(self, default_eager: E) -> E {
         match self {
             Ok(_) => default_eager,
@@ -265,30 +383,78 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
     pub fn expect<S: AsRef<str>>(self, message: S) -> T {
         match self {
             Ok(t) => t,
-            Err(_) => unwrap_failed(<S as AsRef<str>>::as_ref(&message)),
+            Err(_) => unwrap_failed(UnwrapVariant::Err, format_args!("{}", <S as AsRef<str>>::as_ref(&message))),
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn expect_debug<S: AsRef<str>>(self, message: S) -> T
+    where
+        E: fmt::Debug,
+    {
+        match self {
+            Ok(t) => t,
+            Err(e) => unwrap_failed(UnwrapVariant::Err, format_args!("{}: {:?}", <S as AsRef<str>>::as_ref(&message), e)),
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn expect_with(self, message_lazy: impl Fn(&mut fmt::Formatter<'_>) -> fmt::Result) -> T {
+        match self {
+            Ok(t) => t,
+            Err(_) => unwrap_failed(UnwrapVariant::Err, format_args!("{}", FmtWith(message_lazy))),
         }
     }
 
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
     pub fn expect_err<S: AsRef<str>>(self, message: S) -> E {
         match self {
-            Ok(_) => unwrap_failed(<S as AsRef<str>>::as_ref(&message)),
+            Ok(_) => unwrap_failed(UnwrapVariant::Ok, format_args!("{}", <S as AsRef<str>>::as_ref(&message))),
+            Err(e) => e,
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn expect_err_debug<S: AsRef<str>>(self, message: S) -> E
+    where
+        T: fmt::Debug,
+    {
+        match self {
+            Ok(t) => unwrap_failed(UnwrapVariant::Ok, format_args!("{}: {:?}", <S as AsRef<str>>::as_ref(&message), t)),
             Err(e) => e,
         }
     }
 
+    #[cfg(not(feature = "no-panic"))]
+    #[track_caller]
+    pub fn expect_err_with(self, message_lazy: impl Fn(&mut fmt::Formatter<'_>) -> fmt::Result) -> E {
+        match self {
+            Ok(_) => unwrap_failed(UnwrapVariant::Ok, format_args!("{}", FmtWith(message_lazy))),
+            Err(e) => e,
+        }
+    }
+
+    #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn unwrap_unchecked(self) -> T {
         match self {
             Ok(t) => t,
-            Err(_) => unsafe { unreachable_unchecked() },
+            Err(_) => unsafe { debug_unreachable("BResult::unwrap_unchecked called on an Err value") },
         }
     }
 
+    #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn unwrap_err_unchecked(self) -> E {
         match self {
-            Ok(_) => unsafe { unreachable_unchecked() },
+            Ok(_) => unsafe { debug_unreachable("BResult::unwrap_err_unchecked called on an Ok value") },
             Err(e) => e,
         }
     }
@@ -357,6 +523,48 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    pub fn as_is_ok_and(&self, cond: impl FnOnce(&T) -> bool) -> bool {
+        match *self {
+            Ok(ref t) => cond(t),
+            Err(_) => false,
+        }
+    }
+
+    pub fn as_is_ok_or(&self, cond: impl FnOnce(&E) -> bool) -> bool {
+        match *self {
+            Ok(_) => true,
+            Err(ref e) => cond(e),
+        }
+    }
+
+    pub fn as_is_err_and(&self, cond: impl FnOnce(&E) -> bool) -> bool {
+        match *self {
+            Ok(_) => false,
+            Err(ref e) => cond(e),
+        }
+    }
+
+    pub fn as_is_err_or(&self, cond: impl FnOnce(&T) -> bool) -> bool {
+        match *self {
+            Ok(ref t) => cond(t),
+            Err(_) => true,
+        }
+    }
+
+    pub fn as_contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_is_ok_and(|t| t == other)
+    }
+
+    pub fn as_contains_err(&self, other: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.as_is_err_and(|e| e == other)
+    }
+
     pub fn into_boption(self) -> BOption<T> {
         match self {
             Ok(t) => Some(t),
@@ -371,16 +579,18 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn into_boption_unchecked(self) -> BOption<T> {
         match self {
             Ok(t) => Some(t),
-            Err(_) => unsafe { unreachable_unchecked() },
+            Err(_) => unsafe { debug_unreachable("BResult::into_boption_unchecked called on an Err value") },
         }
     }
 
+    #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn into_boption_err_unchecked(self) -> BOption<E> {
         match self {
-            Ok(_) => unsafe { unreachable_unchecked() },
+            Ok(_) => unsafe { debug_unreachable("BResult::into_boption_err_unchecked called on an Ok value") },
             Err(e) => Some(e),
         }
     }
@@ -399,16 +609,18 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn into_option_unchecked(self) -> Option<T> {
         match self {
             Ok(t) => Option::Some(t),
-            Err(_) => unsafe { unreachable_unchecked() },
+            Err(_) => unsafe { debug_unreachable("BResult::into_option_unchecked called on an Err value") },
         }
     }
 
+    #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn into_option_err_unchecked(self) -> Option<E> {
         match self {
-            Ok(_) => unsafe { unreachable_unchecked() },
+            Ok(_) => unsafe { debug_unreachable("BResult::into_option_err_unchecked called on an Ok value") },
             Err(e) => Option::Some(e),
         }
     }
@@ -427,6 +639,40 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    pub fn as_deref(&self) -> BResult<&T::Target, &E>
+    where
+        T: Deref,
+    {
+        match *self {
+            Ok(ref t) => Ok(t.deref()),
+            Err(ref e) => Err(e),
+        }
+    }
+
+    pub fn as_deref_mut(&mut self) -> BResult<&mut T::Target, &mut E>
+    where
+        T: DerefMut,
+    {
+        match *self {
+            Ok(ref mut t) => Ok(t.deref_mut()),
+            Err(ref mut e) => Err(e),
+        }
+    }
+
+    pub fn as_pin_ref(self: Pin<&Self>) -> BResult<Pin<&T>, Pin<&E>> {
+        match *Pin::get_ref(self) {
+            Ok(ref t) => Ok(unsafe { Pin::new_unchecked(t) }),
+            Err(ref e) => Err(unsafe { Pin::new_unchecked(e) }),
+        }
+    }
+
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> BResult<Pin<&mut T>, Pin<&mut E>> {
+        match *unsafe { Pin::get_unchecked_mut(self) } {
+            Ok(ref mut t) => Ok(unsafe { Pin::new_unchecked(t) }),
+            Err(ref mut e) => Err(unsafe { Pin::new_unchecked(e) }),
+        }
+    }
+
     pub fn into_map_ok<U>(self, mapper: impl FnOnce(T) -> U) -> BResult<U, E> {
         match self {
             Ok(t) => Ok(mapper(t)),
@@ -516,6 +762,53 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    pub fn as_replace_ok(&mut self, replacer: T) -> BResult<T, E> {
+        mem::replace(self, Ok(replacer))
+    }
+
+    pub fn as_replace_err(&mut self, replacer_err: E) -> BResult<T, E> {
+        mem::replace(self, Err(replacer_err))
+    }
+
+    pub fn as_map_ok_in_place(&mut self, mapper: impl FnOnce(&mut T)) {
+        match *self {
+            Ok(ref mut t) => mapper(t),
+            Err(_) => {}
+        }
+    }
+
+    pub fn as_map_err_in_place(&mut self, mapper_err: impl FnOnce(&mut E)) {
+        match *self {
+            Ok(_) => {}
+            Err(ref mut e) => mapper_err(e),
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    pub fn as_recover(&mut self, recover: impl FnOnce(E) -> T) -> &mut T {
+        if self.is_err() {
+            let guard = AbortOnUnwind("BResult::as_recover closure unwound, aborting instead of dropping E twice");
+            let e = unsafe { ptr::read(self).unwrap_err_unchecked() };
+            unsafe { ptr::write(self, Ok(recover(e))) };
+            mem::forget(guard);
+        }
+        match *self {
+            Ok(ref mut t) => t,
+            Err(_) => unsafe { debug_unreachable("BResult::as_recover left an Err value behind") },
+        }
+    }
+
+    pub fn as_recover_ref(&mut self, recover: impl FnOnce(&E) -> T) {
+        match *self {
+            Ok(_) => {}
+            Err(ref e) => *self = Ok(recover(e)),
+        }
+    }
+
+    pub fn as_swap_with(&mut self, other: &mut BResult<T, E>) {
+        mem::swap(self, other);
+    }
+
     pub fn into_map_ok_flatten<U>(self, other_if_ok: BResult<U, E>) -> BResult<U, E> {
         match self {
             Ok(_) => other_if_ok,
@@ -544,6 +837,34 @@ impl<T, E> BResult<T, E> {
         }
     }
 
+    pub fn into_nand<U, F>(self, other: BResult<U, F>) -> BResult<(), ()> {
+        match (self, other) {
+            (Ok(_), Ok(_)) => Err(()),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn into_nor<U, F>(self, other: BResult<U, F>) -> BResult<(), ()> {
+        match (self, other) {
+            (Err(_), Err(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    pub fn into_xnor<U, F>(self, other: BResult<U, F>) -> BResult<(), ()> {
+        match (self, other) {
+            (Ok(_), Ok(_)) | (Err(_), Err(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    pub fn into_implies<U, F>(self, other: BResult<U, F>) -> BResult<(), ()> {
+        match (self, other) {
+            (Ok(_), Err(_)) => Err(()),
+            _ => Ok(()),
+        }
+    }
+
     pub const fn into_result(self) -> Result<T, E> {
         let this = ManuallyDrop::new(self);
         match *unsafe { &*manually_drop_as_ptr(&this) } {
@@ -568,6 +889,20 @@ impl<T, E> BResult<T, E> {
         };
         FfiResult { tag, discriminant }
     }
+
+    pub fn into_control_flow(self) -> ControlFlow<E, T> {
+        match self {
+            Ok(t) => ControlFlow::Continue(t),
+            Err(e) => ControlFlow::Break(e),
+        }
+    }
+
+    pub fn from_control_flow(control_flow: ControlFlow<E, T>) -> Self {
+        match control_flow {
+            ControlFlow::Continue(t) => Ok(t),
+            ControlFlow::Break(e) => Err(e),
+        }
+    }
 }
 
 impl<T, E> BResult<&T, E> {
@@ -681,20 +1016,34 @@ impl<E> BResult<Infallible, E> {
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 #[track_caller]
 #[inline(always)]
 fn unwrap_ok_failed_default() -> ! {
-    unwrap_failed("called unwrap on Err value")
+    unwrap_failed(UnwrapVariant::Err, format_args!("called unwrap on Err value"))
 }
 
+#[cfg(not(feature = "no-panic"))]
 #[track_caller]
 #[inline(always)]
 fn unwrap_err_failed_default() -> ! {
-    unwrap_failed("called unwrap_err on Ok value")
+    unwrap_failed(UnwrapVariant::Ok, format_args!("called unwrap_err on Ok value"))
 }
 
+#[cfg(not(feature = "no-panic"))]
 #[track_caller]
 #[inline(never)]
-fn unwrap_failed(message: &str) -> ! {
+fn unwrap_failed(found: UnwrapVariant, message: fmt::Arguments<'_>) -> ! {
+    call_unwrap_failure_hook(&UnwrapFailure::new(message, Location::caller(), found));
     panic!("unwrap failed: {}", message);
 }
+
+#[cfg(not(feature = "no-panic"))]
+struct AbortOnUnwind(&'static str);
+
+#[cfg(not(feature = "no-panic"))]
+impl Drop for AbortOnUnwind {
+    fn drop(&mut self) {
+        panic!("{}", self.0);
+    }
+}
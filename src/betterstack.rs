@@ -0,0 +1,197 @@
+//! ```ignore
+//! BStack<T, N>: fixed-capacity, push-only storage that never allocates
+//!
+//! new() -> BStack<T, N>
+//!
+//! len() -> usize
+//! capacity() -> usize
+//! overflowed() -> usize
+//! is_empty() -> bool
+//! is_not_empty() -> bool
+//! is_full() -> bool
+//! is_not_full() -> bool
+//!
+//! as_push(T) -> BResult<(), T> ?counts overflow
+//! as_pop() -> BOption<T>
+//! as_append(BStack<T, N>) ?counts overflow, including the other stack's
+//! as_clear()
+//!
+//! as_slice() -> &[T]
+//! as_mut_slice() -> &mut [T]
+//! as_iter() -> slice::Iter<T>
+//! into_iter() -> BStackIntoIter<T, N> oldest first
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use core::fmt;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+use core::slice;
+
+pub struct BStack<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    len: usize,
+    overflowed: usize,
+}
+
+impl<T, const N: usize> BStack<T, N> {
+    pub const fn new() -> Self {
+        BStack {
+            items: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+            overflowed: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn overflowed(&self) -> usize {
+        self.overflowed
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_not_empty(&self) -> bool {
+        self.len != 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub const fn is_not_full(&self) -> bool {
+        self.len != N
+    }
+
+    pub fn as_push(&mut self, item: T) -> BResult<(), T> {
+        match self.items.get_mut(self.len) {
+            Option::Some(slot) => {
+                slot.write(item);
+                self.len += 1;
+                Ok(())
+            }
+            Option::None => {
+                self.overflowed += 1;
+                Err(item)
+            }
+        }
+    }
+
+    pub fn as_pop(&mut self) -> BOption<T> {
+        let last = self.len.checked_sub(1).and_then(|last| self.items.get(last));
+        match last {
+            Option::Some(item) => {
+                self.len -= 1;
+                Some(unsafe { item.assume_init_read() })
+            }
+            Option::None => None,
+        }
+    }
+
+    pub fn as_append(&mut self, other: BStack<T, N>) {
+        self.overflowed += other.overflowed;
+        for item in other {
+            let _ = self.as_push(item);
+        }
+    }
+
+    pub fn as_clear(&mut self) {
+        let initialized = self.len;
+        self.len = 0;
+        self.overflowed = 0;
+        for item in self.items.iter_mut().take(initialized) {
+            unsafe { item.assume_init_drop() };
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.items.as_ptr().cast::<T>(), self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.items.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    pub fn as_iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T, const N: usize> Default for BStack<T, N> {
+    fn default() -> Self {
+        BStack::new()
+    }
+}
+
+impl<T, const N: usize> Drop for BStack<T, N> {
+    fn drop(&mut self) {
+        self.as_clear();
+    }
+}
+
+pub struct BStackIntoIter<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    next: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> Iterator for BStackIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next < self.len {
+            let item = self.items.get(self.next)?;
+            self.next += 1;
+            Option::Some(unsafe { item.assume_init_read() })
+        } else {
+            Option::None
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for BStackIntoIter<T, N> {
+    fn drop(&mut self) {
+        for item in self.items.iter_mut().take(self.len).skip(self.next) {
+            unsafe { item.assume_init_drop() };
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for BStack<T, N> {
+    type Item = T;
+    type IntoIter = BStackIntoIter<T, N>;
+
+    fn into_iter(self) -> BStackIntoIter<T, N> {
+        let this = ManuallyDrop::new(self);
+        BStackIntoIter {
+            items: unsafe { ptr::read(&raw const this.items) },
+            next: 0,
+            len: this.len,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a BStack<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_iter()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for BStack<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
@@ -0,0 +1,191 @@
+//! ```ignore
+//! BResult<T, PanicPayload>
+//!
+//! from_catch_unwind(|| T + UnwindSafe) -> BResult<T, PanicPayload>
+//! from_join(JoinHandle<T>) -> BResult<T, PanicPayload>
+//! from_scoped_join(ScopedJoinHandle<T>) -> BResult<T, PanicPayload>
+//!
+//! BResult<G, LockPoisoned<G>>
+//!
+//! from_mutex_lock(&Mutex<T>) -> BResult<MutexGuard<T>, LockPoisoned<MutexGuard<T>>>
+//! from_rwlock_read(&RwLock<T>) -> BResult<RwLockReadGuard<T>, LockPoisoned<RwLockReadGuard<T>>>
+//! from_rwlock_write(&RwLock<T>) -> BResult<RwLockWriteGuard<T>, LockPoisoned<RwLockWriteGuard<T>>>
+//!
+//! into_recover_poisoned() -> G
+//!
+//! PanicPayload
+//!
+//! as_message() -> BOption<&str>
+//! as_any() -> &(dyn Any + Send)
+//! into_any() -> Box<dyn Any + Send>
+//! into_resume_unwind() -> !
+//!
+//! LockPoisoned<G>
+//!
+//! as_guard() -> &G
+//! as_guard_mut() -> &mut G
+//! into_guard() -> G
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use core::any::Any;
+use core::error::Error;
+use core::fmt;
+use std::boxed::Box;
+use std::panic::{self, UnwindSafe};
+use std::string::String;
+use std::sync::{LockResult, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread::{JoinHandle, ScopedJoinHandle};
+
+pub struct PanicPayload {
+    payload: Box<dyn Any + Send + 'static>,
+}
+
+impl PanicPayload {
+    pub fn new(payload: Box<dyn Any + Send + 'static>) -> Self {
+        PanicPayload { payload }
+    }
+
+    pub fn as_message(&self) -> BOption<&str> {
+        if let Option::Some(message) = self.payload.downcast_ref::<&'static str>() {
+            Some(message)
+        } else if let Option::Some(message) = self.payload.downcast_ref::<String>() {
+            Some(message.as_str())
+        } else {
+            None
+        }
+    }
+
+    pub fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        &*self.payload
+    }
+
+    pub fn into_any(self) -> Box<dyn Any + Send + 'static> {
+        self.payload
+    }
+
+    pub fn into_resume_unwind(self) -> ! {
+        panic::resume_unwind(self.payload)
+    }
+}
+
+impl fmt::Debug for PanicPayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_message() {
+            Some(message) => f.debug_tuple("PanicPayload").field(&message).finish(),
+            None => f.debug_tuple("PanicPayload").field(&format_args!("..")).finish(),
+        }
+    }
+}
+
+impl fmt::Display for PanicPayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_message() {
+            Some(message) => write!(f, "panicked: {}", message),
+            None => f.write_str("panicked with a non-string payload"),
+        }
+    }
+}
+
+impl Error for PanicPayload {}
+
+pub struct LockPoisoned<G> {
+    guard: G,
+}
+
+impl<G> LockPoisoned<G> {
+    pub fn new(guard: G) -> Self {
+        LockPoisoned { guard }
+    }
+
+    pub fn as_guard(&self) -> &G {
+        &self.guard
+    }
+
+    pub fn as_guard_mut(&mut self) -> &mut G {
+        &mut self.guard
+    }
+
+    pub fn into_guard(self) -> G {
+        self.guard
+    }
+}
+
+impl<G> From<PoisonError<G>> for LockPoisoned<G> {
+    fn from(poison: PoisonError<G>) -> Self {
+        LockPoisoned::new(poison.into_inner())
+    }
+}
+
+impl<G> fmt::Debug for LockPoisoned<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LockPoisoned").finish_non_exhaustive()
+    }
+}
+
+impl<G> fmt::Display for LockPoisoned<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("lock poisoned by a panicking thread")
+    }
+}
+
+impl<G> Error for LockPoisoned<G> {}
+
+fn from_lock_result<G>(result: LockResult<G>) -> BResult<G, LockPoisoned<G>> {
+    match result {
+        Result::Ok(guard) => Ok(guard),
+        Result::Err(poison) => Err(LockPoisoned::from(poison)),
+    }
+}
+
+impl<T> BResult<T, PanicPayload> {
+    pub fn from_catch_unwind(f: impl FnOnce() -> T + UnwindSafe) -> Self {
+        match panic::catch_unwind(f) {
+            Result::Ok(t) => Ok(t),
+            Result::Err(payload) => Err(PanicPayload::new(payload)),
+        }
+    }
+
+    pub fn from_join(handle: JoinHandle<T>) -> Self {
+        match handle.join() {
+            Result::Ok(t) => Ok(t),
+            Result::Err(payload) => Err(PanicPayload::new(payload)),
+        }
+    }
+
+    pub fn from_scoped_join(handle: ScopedJoinHandle<'_, T>) -> Self {
+        match handle.join() {
+            Result::Ok(t) => Ok(t),
+            Result::Err(payload) => Err(PanicPayload::new(payload)),
+        }
+    }
+}
+
+impl<'a, T: ?Sized> BResult<MutexGuard<'a, T>, LockPoisoned<MutexGuard<'a, T>>> {
+    pub fn from_mutex_lock(mutex: &'a Mutex<T>) -> Self {
+        from_lock_result(mutex.lock())
+    }
+}
+
+impl<'a, T: ?Sized> BResult<RwLockReadGuard<'a, T>, LockPoisoned<RwLockReadGuard<'a, T>>> {
+    pub fn from_rwlock_read(rwlock: &'a RwLock<T>) -> Self {
+        from_lock_result(rwlock.read())
+    }
+}
+
+impl<'a, T: ?Sized> BResult<RwLockWriteGuard<'a, T>, LockPoisoned<RwLockWriteGuard<'a, T>>> {
+    pub fn from_rwlock_write(rwlock: &'a RwLock<T>) -> Self {
+        from_lock_result(rwlock.write())
+    }
+}
+
+impl<G> BResult<G, LockPoisoned<G>> {
+    pub fn into_recover_poisoned(self) -> G {
+        match self {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_guard(),
+        }
+    }
+}
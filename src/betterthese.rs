@@ -0,0 +1,250 @@
+//! ```ignore
+//! BThese<A, B>: This(A), That(B) or Both(A, B)
+//!
+//! from_boptions(BOption<A>, BOption<B>) -> BOption<BThese<A, B>> None if both are None
+//! BOption::into_these(BOption<B>) -> BOption<BThese<T, B>>
+//!
+//! is_this()      -> bool only This
+//! is_not_this()  -> bool
+//! is_that()      -> bool only That
+//! is_not_that()  -> bool
+//! is_both()      -> bool
+//! is_not_both()  -> bool
+//!
+//! as_ref() -> BThese<&A, &B>
+//! as_mut() -> BThese<&mut A, &mut B>
+//!
+//! into_swapped() -> BThese<B, A>
+//!
+//! into_boption_this     () -> BOption<A> This or Both, Drops B
+//! into_boption_that     () -> BOption<B> That or Both, Drops A
+//! into_boption_only_this() -> BOption<A> only This
+//! into_boption_only_that() -> BOption<B> only That
+//! into_boption_both     () -> BOption<(A, B)> only Both
+//! into_boptions         () -> (BOption<A>, BOption<B>)
+//!
+//! for <C>: mapping A into C
+//! into_map_this(|A| C ?Drops A) -> BThese<C, B>
+//! for <D>: mapping B into D
+//! into_map_that(|B| D ?Drops B) -> BThese<A, D>
+//! into_map_both(|A| C ?Drops A, |B| D ?Drops B) -> BThese<C, D>
+//!
+//! for <C>: merging A and B into C
+//! into_merge_with(|A| C, |B| C, |A, B| C) -> C
+//!
+//! where A = B = T
+//! into_merge(|T, T| T) -> T
+//! into_iter() -> BTheseIter<T> this first, then that
+//! as_iter() -> BTheseIter<&T>
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+
+pub enum BThese<A, B> {
+    This(A),
+    That(B),
+    Both(A, B),
+}
+use BThese::{Both, That, This};
+
+impl<A, B> BThese<A, B> {
+    pub fn from_boptions(this: BOption<A>, that: BOption<B>) -> BOption<Self> {
+        match (this, that) {
+            (Some(a), Some(b)) => Some(Both(a, b)),
+            (Some(a), None) => Some(This(a)),
+            (None, Some(b)) => Some(That(b)),
+            (None, None) => None,
+        }
+    }
+
+    pub const fn is_this(&self) -> bool {
+        match *self {
+            This(_) => true,
+            That(_) | Both(_, _) => false,
+        }
+    }
+
+    pub const fn is_not_this(&self) -> bool {
+        match *self {
+            This(_) => false,
+            That(_) | Both(_, _) => true,
+        }
+    }
+
+    pub const fn is_that(&self) -> bool {
+        match *self {
+            That(_) => true,
+            This(_) | Both(_, _) => false,
+        }
+    }
+
+    pub const fn is_not_that(&self) -> bool {
+        match *self {
+            That(_) => false,
+            This(_) | Both(_, _) => true,
+        }
+    }
+
+    pub const fn is_both(&self) -> bool {
+        match *self {
+            Both(_, _) => true,
+            This(_) | That(_) => false,
+        }
+    }
+
+    pub const fn is_not_both(&self) -> bool {
+        match *self {
+            Both(_, _) => false,
+            This(_) | That(_) => true,
+        }
+    }
+
+    pub const fn as_ref(&self) -> BThese<&A, &B> {
+        match *self {
+            This(ref a) => This(a),
+            That(ref b) => That(b),
+            Both(ref a, ref b) => Both(a, b),
+        }
+    }
+
+    pub const fn as_mut(&mut self) -> BThese<&mut A, &mut B> {
+        match *self {
+            This(ref mut a) => This(a),
+            That(ref mut b) => That(b),
+            Both(ref mut a, ref mut b) => Both(a, b),
+        }
+    }
+
+    pub fn into_swapped(self) -> BThese<B, A> {
+        match self {
+            This(a) => That(a),
+            That(b) => This(b),
+            Both(a, b) => Both(b, a),
+        }
+    }
+
+    pub fn into_boption_this(self) -> BOption<A> {
+        match self {
+            This(a) | Both(a, _) => Some(a),
+            That(_) => None,
+        }
+    }
+
+    pub fn into_boption_that(self) -> BOption<B> {
+        match self {
+            That(b) | Both(_, b) => Some(b),
+            This(_) => None,
+        }
+    }
+
+    pub fn into_boption_only_this(self) -> BOption<A> {
+        match self {
+            This(a) => Some(a),
+            That(_) | Both(_, _) => None,
+        }
+    }
+
+    pub fn into_boption_only_that(self) -> BOption<B> {
+        match self {
+            That(b) => Some(b),
+            This(_) | Both(_, _) => None,
+        }
+    }
+
+    pub fn into_boption_both(self) -> BOption<(A, B)> {
+        match self {
+            Both(a, b) => Some((a, b)),
+            This(_) | That(_) => None,
+        }
+    }
+
+    pub fn into_boptions(self) -> (BOption<A>, BOption<B>) {
+        match self {
+            This(a) => (Some(a), None),
+            That(b) => (None, Some(b)),
+            Both(a, b) => (Some(a), Some(b)),
+        }
+    }
+
+    pub fn into_map_this<C>(self, mapper_this: impl FnOnce(A) -> C) -> BThese<C, B> {
+        match self {
+            This(a) => This(mapper_this(a)),
+            That(b) => That(b),
+            Both(a, b) => Both(mapper_this(a), b),
+        }
+    }
+
+    pub fn into_map_that<D>(self, mapper_that: impl FnOnce(B) -> D) -> BThese<A, D> {
+        match self {
+            This(a) => This(a),
+            That(b) => That(mapper_that(b)),
+            Both(a, b) => Both(a, mapper_that(b)),
+        }
+    }
+
+    pub fn into_map_both<C, D>(self, mapper_this: impl FnOnce(A) -> C, mapper_that: impl FnOnce(B) -> D) -> BThese<C, D> {
+        match self {
+            This(a) => This(mapper_this(a)),
+            That(b) => That(mapper_that(b)),
+            Both(a, b) => Both(mapper_this(a), mapper_that(b)),
+        }
+    }
+
+    pub fn into_merge_with<C>(
+        self,
+        mapper_this: impl FnOnce(A) -> C,
+        mapper_that: impl FnOnce(B) -> C,
+        merger: impl FnOnce(A, B) -> C,
+    ) -> C {
+        match self {
+            This(a) => mapper_this(a),
+            That(b) => mapper_that(b),
+            Both(a, b) => merger(a, b),
+        }
+    }
+}
+
+impl<T> BThese<T, T> {
+    pub fn into_merge(self, merger: impl FnOnce(T, T) -> T) -> T {
+        match self {
+            This(t) | That(t) => t,
+            Both(this, that) => merger(this, that),
+        }
+    }
+
+    pub fn as_iter(&self) -> BTheseIter<&T> {
+        self.as_ref().into_iter()
+    }
+}
+
+pub struct BTheseIter<T> {
+    first: BOption<T>,
+    second: BOption<T>,
+}
+
+impl<T> Iterator for BTheseIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self.first.as_take() {
+            Some(t) => Option::Some(t),
+            None => self.second.as_take().into_option(),
+        }
+    }
+}
+
+impl<T> IntoIterator for BThese<T, T> {
+    type Item = T;
+    type IntoIter = BTheseIter<T>;
+
+    fn into_iter(self) -> BTheseIter<T> {
+        let (first, second) = self.into_boptions();
+        BTheseIter { first, second }
+    }
+}
+
+impl<T> BOption<T> {
+    pub fn into_these<B>(self, other: BOption<B>) -> BOption<BThese<T, B>> {
+        BThese::from_boptions(self, other)
+    }
+}
@@ -0,0 +1,234 @@
+//! ```ignore
+//! Traced<E, N = 8>: an error plus up to N caller locations it travelled through.
+//! Locations are only recorded with the `trace` feature, otherwise Traced<E> is just E.
+//!
+//! new(E) -> Traced<E, N> ?records caller location
+//! From<E> ?records caller location, so `Traced::from` and `.into()` are traced too
+//!
+//! as_error() -> &E
+//! as_error_mut() -> &mut E
+//! into_error() -> E
+//! into_map(|E| F) -> Traced<F, N> keeps the trace
+//!
+//! as_push_location() ?records caller location
+//! as_trace() -> &[&Location] oldest first, empty without `trace`
+//! overflowed() -> usize
+//! as_trace_report() -> TraceReport prints the error followed by `at file:line:column` lines
+//!
+//! BResult<T, E>
+//! into_traced() -> BResult<T, Traced<E, N>> ?records caller location
+//!
+//! BResult<T, Traced<E, N>>, only these record a location, plain into_map_err and friends record nothing
+//! into_untraced() -> BResult<T, E>
+//! into_retraced() -> BResult<T, Traced<E, N>> ?records caller location
+//! into_traced_map_err(|E| F) -> BResult<T, Traced<F, N>> ?records caller location
+//! into_traced_map_ok_flatten_lazy(|T| BResult<U, Traced<E, N>>) -> BResult<U, Traced<E, N>> ?records caller location
+//! into_traced_map_err_flatten_lazy(|E| BResult<T, F>) -> BResult<T, Traced<F, N>> ?records caller location
+//! ```
+#[cfg(feature = "trace")]
+use crate::betterstack::BStack;
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use core::error::Error;
+use core::fmt;
+use core::panic::Location;
+
+pub struct Traced<E, const N: usize = 8> {
+    error: E,
+    #[cfg(feature = "trace")]
+    trace: BStack<&'static Location<'static>, N>,
+}
+
+impl<E, const N: usize> Traced<E, N> {
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn new(error: E) -> Self {
+        let mut traced = Traced {
+            error,
+            #[cfg(feature = "trace")]
+            trace: BStack::new(),
+        };
+        traced.as_push_location();
+        traced
+    }
+
+    pub const fn as_error(&self) -> &E {
+        &self.error
+    }
+
+    pub const fn as_error_mut(&mut self) -> &mut E {
+        &mut self.error
+    }
+
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    pub fn into_map<F>(self, mapper: impl FnOnce(E) -> F) -> Traced<F, N> {
+        Traced {
+            error: mapper(self.error),
+            #[cfg(feature = "trace")]
+            trace: self.trace,
+        }
+    }
+
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn as_push_location(&mut self) {
+        #[cfg(feature = "trace")]
+        {
+            let _ = self.trace.as_push(Location::caller());
+        }
+    }
+
+    pub fn as_trace(&self) -> &[&'static Location<'static>] {
+        #[cfg(feature = "trace")]
+        {
+            self.trace.as_slice()
+        }
+        #[cfg(not(feature = "trace"))]
+        {
+            &[]
+        }
+    }
+
+    pub const fn overflowed(&self) -> usize {
+        #[cfg(feature = "trace")]
+        {
+            self.trace.overflowed()
+        }
+        #[cfg(not(feature = "trace"))]
+        {
+            0
+        }
+    }
+
+    pub fn as_trace_report(&self) -> TraceReport<'_, E, N> {
+        TraceReport { traced: self }
+    }
+}
+
+impl<E, const N: usize> From<E> for Traced<E, N> {
+    #[cfg_attr(feature = "trace", track_caller)]
+    fn from(error: E) -> Self {
+        Traced::new(error)
+    }
+}
+
+impl<E: fmt::Display, const N: usize> fmt::Display for Traced<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl<E: fmt::Debug, const N: usize> fmt::Debug for Traced<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Traced")
+            .field("error", &self.error)
+            .field("trace", &self.as_trace())
+            .finish()
+    }
+}
+
+impl<E: Error, const N: usize> Error for Traced<E, N> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+pub struct TraceReport<'a, E, const N: usize> {
+    traced: &'a Traced<E, N>,
+}
+
+impl<E: fmt::Display, const N: usize> fmt::Display for TraceReport<'_, E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.traced.error)?;
+        for location in self.traced.as_trace() {
+            write!(f, "\n    at {}", location)?;
+        }
+        if self.traced.overflowed() != 0 {
+            write!(f, "\n    ... {} more", self.traced.overflowed())?;
+        }
+        Result::Ok(())
+    }
+}
+
+impl<T, E> BResult<T, E> {
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn into_traced<const N: usize>(self) -> BResult<T, Traced<E, N>> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(e) => Err(Traced::new(e)),
+        }
+    }
+}
+
+impl<T, E, const N: usize> BResult<T, Traced<E, N>> {
+    pub fn into_untraced(self) -> BResult<T, E> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(traced) => Err(traced.into_error()),
+        }
+    }
+
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn into_retraced(self) -> BResult<T, Traced<E, N>> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(mut traced) => {
+                traced.as_push_location();
+                Err(traced)
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn into_traced_map_err<F>(self, mapper_err: impl FnOnce(E) -> F) -> BResult<T, Traced<F, N>> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(traced) => {
+                let mut traced = traced.into_map(mapper_err);
+                traced.as_push_location();
+                Err(traced)
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn into_traced_map_ok_flatten_lazy<U>(
+        self,
+        other_if_ok_lazy: impl FnOnce(T) -> BResult<U, Traced<E, N>>,
+    ) -> BResult<U, Traced<E, N>> {
+        let result = match self {
+            Ok(t) => other_if_ok_lazy(t),
+            Err(traced) => Err(traced),
+        };
+        result.into_retraced()
+    }
+
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn into_traced_map_err_flatten_lazy<F>(
+        self,
+        other_if_err_lazy: impl FnOnce(E) -> BResult<T, F>,
+    ) -> BResult<T, Traced<F, N>> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(traced) => {
+                #[cfg(feature = "trace")]
+                let Traced { error, trace } = traced;
+                #[cfg(not(feature = "trace"))]
+                let Traced { error } = traced;
+                match other_if_err_lazy(error) {
+                    Ok(t) => Ok(t),
+                    Err(f) => {
+                        let mut traced = Traced {
+                            error: f,
+                            #[cfg(feature = "trace")]
+                            trace,
+                        };
+                        traced.as_push_location();
+                        Err(traced)
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! ```ignore
+//! Accumulator: where errors end up, BStack<E, N> without an allocator, Vec<E> with `alloc`
+//!
+//! as_accumulate(Item)
+//! as_accumulate_all(Self)
+//! is_accumulated() -> bool anything accumulated, counting BStack overflow
+//!
+//! BValidation<T, S> where S: Accumulator
+//!
+//! is_valid()       -> bool
+//! is_not_valid()   -> bool
+//! is_invalid()     -> bool
+//! is_not_invalid() -> bool
+//!
+//! from_error(S::Item) -> BValidation<T, S>
+//! from_bresult(BResult<T, S::Item>) -> BValidation<T, S>
+//! BResult::into_bvalidation() -> BValidation<T, S>
+//! into_bresult() -> BResult<T, S>
+//!
+//! as_ref() -> BValidation<&T, &S>
+//! into_boption_valid() -> BOption<T> Drops S
+//! into_boption_invalid() -> BOption<S> Drops T
+//!
+//! for <U>: mapping T into U
+//! into_map_valid(|T| U ?Drops T) -> BValidation<U, S>
+//! into_map_valid_flatten_lazy(|T| BValidation<U, S> ?Drops T) -> BValidation<U, S> stops at the first Invalid
+//!
+//! for <U>: combining T and U, errors of both sides are kept
+//! into_zip(BValidation<U, S>) -> BValidation<(T, U), S>
+//! into_zip_with(BValidation<U, S>, |T, U| V) -> BValidation<V, S>
+//! into_valid_or_accumulate(&mut S) -> BOption<T> moves errors into S
+//!
+//! bvalidate!(Constructor { field: BValidation<_, S>, .. }) -> BValidation<Constructor, S>
+//! ```
+use crate::betteroption::BOption;
+use crate::betteroption::BOption::{None, Some};
+use crate::betterresult::BResult;
+use crate::betterresult::BResult::{Err, Ok};
+use crate::betterstack::BStack;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+pub trait Accumulator: Default {
+    type Item;
+
+    fn as_accumulate(&mut self, item: Self::Item);
+
+    fn as_accumulate_all(&mut self, other: Self);
+
+    fn is_accumulated(&self) -> bool;
+}
+
+impl<E, const N: usize> Accumulator for BStack<E, N> {
+    type Item = E;
+
+    fn as_accumulate(&mut self, item: E) {
+        let _ = self.as_push(item);
+    }
+
+    fn as_accumulate_all(&mut self, other: Self) {
+        self.as_append(other);
+    }
+
+    fn is_accumulated(&self) -> bool {
+        self.is_not_empty() || self.overflowed() != 0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> Accumulator for Vec<E> {
+    type Item = E;
+
+    fn as_accumulate(&mut self, item: E) {
+        self.push(item);
+    }
+
+    fn as_accumulate_all(&mut self, mut other: Self) {
+        self.append(&mut other);
+    }
+
+    fn is_accumulated(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+pub enum BValidation<T, S> {
+    Valid(T),
+    Invalid(S),
+}
+use BValidation::{Invalid, Valid};
+
+impl<T, S: Accumulator> BValidation<T, S> {
+    pub const fn is_valid(&self) -> bool {
+        match *self {
+            Valid(_) => true,
+            Invalid(_) => false,
+        }
+    }
+
+    pub const fn is_not_valid(&self) -> bool {
+        match *self {
+            Valid(_) => false,
+            Invalid(_) => true,
+        }
+    }
+
+    pub const fn is_invalid(&self) -> bool {
+        match *self {
+            Valid(_) => false,
+            Invalid(_) => true,
+        }
+    }
+
+    pub const fn is_not_invalid(&self) -> bool {
+        match *self {
+            Valid(_) => true,
+            Invalid(_) => false,
+        }
+    }
+
+    pub fn from_error(error: S::Item) -> Self {
+        let mut errors = S::default();
+        errors.as_accumulate(error);
+        Invalid(errors)
+    }
+
+    pub fn from_bresult(result: BResult<T, S::Item>) -> Self {
+        match result {
+            Ok(t) => Valid(t),
+            Err(e) => BValidation::from_error(e),
+        }
+    }
+
+    pub fn into_bresult(self) -> BResult<T, S> {
+        match self {
+            Valid(t) => Ok(t),
+            Invalid(errors) => Err(errors),
+        }
+    }
+
+    pub const fn as_ref(&self) -> BValidation<&T, &S> {
+        match *self {
+            Valid(ref t) => Valid(t),
+            Invalid(ref errors) => Invalid(errors),
+        }
+    }
+
+    pub fn into_boption_valid(self) -> BOption<T> {
+        match self {
+            Valid(t) => Some(t),
+            Invalid(_) => None,
+        }
+    }
+
+    pub fn into_boption_invalid(self) -> BOption<S> {
+        match self {
+            Valid(_) => None,
+            Invalid(errors) => Some(errors),
+        }
+    }
+
+    pub fn into_map_valid<U>(self, mapper: impl FnOnce(T) -> U) -> BValidation<U, S> {
+        match self {
+            Valid(t) => Valid(mapper(t)),
+            Invalid(errors) => Invalid(errors),
+        }
+    }
+
+    pub fn into_map_valid_flatten_lazy<U>(self, other_if_valid_lazy: impl FnOnce(T) -> BValidation<U, S>) -> BValidation<U, S> {
+        match self {
+            Valid(t) => other_if_valid_lazy(t),
+            Invalid(errors) => Invalid(errors),
+        }
+    }
+
+    pub fn into_zip<U>(self, other: BValidation<U, S>) -> BValidation<(T, U), S> {
+        self.into_zip_with(other, |t, u| (t, u))
+    }
+
+    pub fn into_zip_with<U, V>(self, other: BValidation<U, S>, zipper: impl FnOnce(T, U) -> V) -> BValidation<V, S> {
+        match (self, other) {
+            (Valid(t), Valid(u)) => Valid(zipper(t, u)),
+            (Valid(_), Invalid(errors)) | (Invalid(errors), Valid(_)) => Invalid(errors),
+            (Invalid(mut errors), Invalid(other_errors)) => {
+                errors.as_accumulate_all(other_errors);
+                Invalid(errors)
+            }
+        }
+    }
+
+    pub fn into_valid_or_accumulate(self, errors: &mut S) -> BOption<T> {
+        match self {
+            Valid(t) => Some(t),
+            Invalid(other_errors) => {
+                errors.as_accumulate_all(other_errors);
+                None
+            }
+        }
+    }
+}
+
+impl<T, E> BResult<T, E> {
+    pub fn into_bvalidation<S: Accumulator<Item = E>>(self) -> BValidation<T, S> {
+        BValidation::from_bresult(self)
+    }
+}
+
+#[macro_export]
+macro_rules! bvalidate {
+    ($($constructor:ident)::+ { $($field:ident: $validation:expr),+ $(,)? }) => {{
+        let mut errors = ::core::default::Default::default();
+        match ($($crate::bettervalidation::BValidation::into_valid_or_accumulate($validation, &mut errors),)+) {
+            ($($crate::betteroption::BOption::Some($field),)+) => {
+                $crate::bettervalidation::BValidation::Valid($($constructor)::+ { $($field),+ })
+            }
+            _ => $crate::bettervalidation::BValidation::Invalid(errors),
+        }
+    }};
+}
@@ -2,10 +2,55 @@
 //! conventions in Rust. `is`, `as`, `to`, `into` all the way through.
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+pub mod betteranyerror;
+pub mod betterdiagnosed;
+pub mod bettereither;
+pub mod betterentry;
+pub mod bettererror;
+pub mod betterfuture;
+pub mod betterhook;
+pub mod betternbresult;
 pub mod betteroption;
+pub mod betteroutcome;
+pub mod betterremote;
+pub mod betterreport;
 pub mod betterresult;
+pub mod betterstack;
+pub mod betterthese;
+pub mod bettertrace;
+pub mod bettervalidation;
+#[cfg(feature = "std")]
+pub mod betterio;
+#[cfg(feature = "std")]
+pub mod betterstd;
 
 pub mod prelude {
+    #[cfg(feature = "alloc")]
+    pub use super::betteranyerror::{BAnyError, BAnyResult};
+    pub use super::betterdiagnosed::BDiagnosed;
+    pub use super::bettereither::BEither;
+    pub use super::betterentry::{BOptionEntry, OccupiedEntry, VacantEntry};
+    pub use super::bettererror::{BError, ContextFrame};
+    pub use super::betterfuture::{BOptionFuture, BResultErrFuture, BResultFuture};
+    pub use super::betterhook::{UnwrapFailure, UnwrapVariant, set_unwrap_failure_hook};
+    pub use super::betternbresult::BNbResult;
     pub use super::betteroption::{BOption, IntoBOption};
+    pub use super::betteroutcome::BOutcome;
+    pub use super::betterremote::BRemote;
+    pub use super::betterreport::{ErrChain, ErrReport};
     pub use super::betterresult::{BResult, IntoBResult};
+    pub use super::betterstack::BStack;
+    pub use super::betterthese::BThese;
+    pub use super::bettertrace::Traced;
+    pub use super::bettervalidation::{Accumulator, BValidation};
+    #[cfg(feature = "std")]
+    pub use super::betterio::BIoResult;
+    #[cfg(feature = "std")]
+    pub use super::betterstd::{LockPoisoned, PanicPayload};
 }
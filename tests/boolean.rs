@@ -0,0 +1,116 @@
+use better_option_result::prelude::*;
+
+const TABLE: [(bool, bool); 4] = [(false, false), (false, true), (true, false), (true, true)];
+
+fn boption(value: bool) -> BOption<u8> {
+    if value { BOption::Some(1) } else { BOption::None }
+}
+
+fn bresult(value: bool) -> BResult<u8, u16> {
+    if value { BResult::Ok(1) } else { BResult::Err(2) }
+}
+
+fn check(
+    expected: impl Fn(bool, bool) -> bool,
+    boption_op: impl Fn(BOption<u8>, BOption<u8>) -> bool,
+    bresult_op: impl Fn(BResult<u8, u16>, BResult<u8, u16>) -> bool,
+) {
+    for (a, b) in TABLE {
+        assert_eq!(
+            boption_op(boption(a), boption(b)),
+            expected(a, b),
+            "BOption: {a} and {b}"
+        );
+        assert_eq!(
+            bresult_op(bresult(a), bresult(b)),
+            expected(a, b),
+            "BResult: {a} and {b}"
+        );
+    }
+}
+
+#[test]
+fn nand() {
+    check(
+        |a, b| !(a && b),
+        |a, b| a.into_nand(b).is_some(),
+        |a, b| a.into_nand(b).is_ok(),
+    );
+}
+
+#[test]
+fn nor() {
+    check(
+        |a, b| !(a || b),
+        |a, b| a.into_nor(b).is_some(),
+        |a, b| a.into_nor(b).is_ok(),
+    );
+}
+
+#[test]
+fn xnor() {
+    check(
+        |a, b| a == b,
+        |a, b| a.into_xnor(b).is_some(),
+        |a, b| a.into_xnor(b).is_ok(),
+    );
+}
+
+#[test]
+fn implies() {
+    check(
+        |a, b| !a || b,
+        |a, b| a.into_implies(b).is_some(),
+        |a, b| a.into_implies(b).is_ok(),
+    );
+}
+
+#[test]
+fn bitand() {
+    for (a, b) in TABLE {
+        let other = if b { BOption::Some('b') } else { BOption::None };
+        assert_eq!((boption(a) & other).into_option(), (a && b).then_some('b'));
+
+        let mut assigned = boption(a);
+        assigned &= boption(b).into_map(|t| t + 1);
+        assert_eq!(assigned.into_option(), (a && b).then_some(2));
+    }
+}
+
+#[test]
+fn bitor() {
+    for (a, b) in TABLE {
+        let left = if a { BOption::Some('a') } else { BOption::None };
+        let right = if b { BOption::Some('b') } else { BOption::None };
+        let expected = if a { Some('a') } else { b.then_some('b') };
+        assert_eq!((left | right).into_option(), expected);
+
+        let mut assigned = if a { BOption::Some('a') } else { BOption::None };
+        assigned |= if b { BOption::Some('b') } else { BOption::None };
+        assert_eq!(assigned.into_option(), expected);
+    }
+}
+
+#[test]
+fn bitxor() {
+    for (a, b) in TABLE {
+        let left = if a { BOption::Some('a') } else { BOption::None };
+        let right = if b { BOption::Some('b') } else { BOption::None };
+        let expected = match (a, b) {
+            (true, false) => Some('a'),
+            (false, true) => Some('b'),
+            _ => None,
+        };
+        assert_eq!((left ^ right).into_option(), expected);
+
+        let mut assigned = if a { BOption::Some('a') } else { BOption::None };
+        assigned ^= if b { BOption::Some('b') } else { BOption::None };
+        assert_eq!(assigned.into_option(), expected);
+    }
+}
+
+#[test]
+fn not() {
+    assert!((!boption(true)).is_none());
+    assert!((!boption(false)).is_some());
+}
@@ -0,0 +1,40 @@
+use better_option_result::prelude::*;
+
+#[test]
+fn or_insert_with_skips_the_closure_when_occupied() {
+    let mut slot = BOption::Some(1_u8);
+    let mut called = false;
+    let value = slot.as_entry().or_insert_with(|| {
+        called = true;
+        2
+    });
+    assert_eq!(*value, 1);
+    assert!(!called);
+}
+
+#[test]
+fn and_modify_on_vacant_is_a_no_op() {
+    let mut slot = BOption::<u8>::None;
+    let mut called = false;
+    let entry = slot.as_entry().and_modify(|_| called = true);
+    assert!(matches!(entry, BOptionEntry::Vacant(_)));
+    assert!(!called);
+    assert!(slot.is_none());
+}
+
+#[test]
+fn occupied_insert_returns_the_old_value() {
+    let mut slot = BOption::Some(1_u8);
+    let BOptionEntry::Occupied(mut occupied) = slot.as_entry() else { panic!("expected Occupied") };
+    assert_eq!(occupied.insert(2), 1);
+    assert_eq!(*occupied.get(), 2);
+    assert!(matches!(slot, BOption::Some(2)));
+}
+
+#[test]
+fn occupied_remove_leaves_none_behind() {
+    let mut slot = BOption::Some(1_u8);
+    let BOptionEntry::Occupied(occupied) = slot.as_entry() else { panic!("expected Occupied") };
+    assert_eq!(occupied.remove(), 1);
+    assert!(slot.is_none());
+}
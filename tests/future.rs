@@ -0,0 +1,101 @@
+use better_option_result::prelude::*;
+use core::future::Future;
+use core::pin::{Pin, pin};
+use core::task::{Context, Poll, Waker};
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+struct YieldOnce<T> {
+    value: T,
+    yielded: bool,
+}
+
+impl<T: Copy + Unpin> Future for YieldOnce<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+        if self.yielded {
+            Poll::Ready(self.value)
+        } else {
+            self.yielded = true;
+            Poll::Pending
+        }
+    }
+}
+
+fn into_std<T, E>(result: BResult<T, E>) -> Result<T, E> {
+    match result {
+        BResult::Ok(t) => Ok(t),
+        BResult::Err(e) => Err(e),
+    }
+}
+
+const fn yield_once<T>(value: T) -> YieldOnce<T> {
+    YieldOnce { value, yielded: false }
+}
+
+#[test]
+fn boption_transposed_future() {
+    let some = BOption::Some(yield_once(3)).into_transposed_future();
+    assert_eq!(block_on(some).into_option(), Some(3));
+
+    let none = BOption::<YieldOnce<i32>>::None.into_transposed_future();
+    assert_eq!(block_on(none).into_option(), None);
+}
+
+#[test]
+fn boption_map_async() {
+    let mapped = BOption::Some(4).into_map_async(|t| async move { t * 2 });
+    assert_eq!(block_on(mapped).into_option(), Some(8));
+
+    let mut called = false;
+    let skipped = BOption::<i32>::None.into_map_async(|t| {
+        called = true;
+        async move { t }
+    });
+    assert_eq!(block_on(skipped).into_option(), None);
+    assert!(!called);
+}
+
+#[test]
+fn bresult_transposed_future() {
+    let ok = BResult::<_, &str>::Ok(yield_once(5)).into_transposed_future();
+    assert_eq!(into_std(block_on(ok)), Ok(5));
+
+    let err = BResult::<YieldOnce<i32>, _>::Err("failed").into_transposed_future();
+    assert_eq!(into_std(block_on(err)), Err("failed"));
+}
+
+#[test]
+fn bresult_transposed_err_future() {
+    let ok = BResult::<_, YieldOnce<&str>>::Ok(5).into_transposed_err_future();
+    assert_eq!(into_std(block_on(ok)), Ok(5));
+
+    let err = BResult::<i32, _>::Err(yield_once("failed")).into_transposed_err_future();
+    assert_eq!(into_std(block_on(err)), Err("failed"));
+}
+
+#[test]
+fn bresult_map_async() {
+    let ok = BResult::<i32, &str>::Ok(6).into_map_ok_async(|t| async move { t + 1 });
+    assert_eq!(into_std(block_on(ok)), Ok(7));
+
+    let err = BResult::<i32, &str>::Err("failed").into_map_err_async(|e| async move { e.len() });
+    assert_eq!(into_std(block_on(err)), Err(6));
+}
+
+#[test]
+fn bresult_future_stays_pending_after_completion() {
+    let mut err = pin!(BResult::<YieldOnce<i32>, _>::Err("failed").into_transposed_future());
+    let mut cx = Context::from_waker(Waker::noop());
+    assert!(matches!(err.as_mut().poll(&mut cx), Poll::Ready(BResult::Err("failed"))));
+    assert!(err.as_mut().poll(&mut cx).is_pending());
+}
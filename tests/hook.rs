@@ -0,0 +1,29 @@
+#![cfg(not(feature = "no-panic"))]
+
+use better_option_result::betterhook::take_unwrap_failure_hook;
+use better_option_result::prelude::*;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+
+static SEEN_LINE: AtomicU32 = AtomicU32::new(0);
+static SEEN_NONE: AtomicU8 = AtomicU8::new(0);
+
+fn record(failure: &UnwrapFailure<'_>) {
+    SEEN_LINE.store(failure.location().line(), Ordering::SeqCst);
+    if failure.found() == UnwrapVariant::None {
+        SEEN_NONE.store(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn hook_sees_variant_and_caller_location() {
+    set_unwrap_failure_hook(record);
+    let none = BOption::<u8>::None;
+    let expected_line = line!() + 1;
+    let unwound = panic::catch_unwind(AssertUnwindSafe(|| none.unwrap()));
+    assert!(unwound.is_err());
+    assert_eq!(SEEN_NONE.load(Ordering::SeqCst), 1);
+    assert_eq!(SEEN_LINE.load(Ordering::SeqCst), expected_line);
+    assert!(take_unwrap_failure_hook().is_some());
+    assert!(take_unwrap_failure_hook().is_none());
+}
@@ -0,0 +1,123 @@
+//! Proves that the non-panicking surface has no unwinding paths: the guard's drop glue calls a
+//! symbol that doesn't exist, so linking fails if anything between the guard's creation and its
+//! `mem::forget` can panic. Only meaningful with optimizations, so the check is ignored in debug
+//! builds; run it with `cargo test --release --features no-panic`.
+#![cfg(feature = "no-panic")]
+#![cfg_attr(debug_assertions, allow(clippy::forget_non_drop))]
+
+use better_option_result::prelude::*;
+use core::hint::black_box;
+use core::mem;
+
+struct PanicPathDetected;
+
+#[cfg(not(debug_assertions))]
+impl Drop for PanicPathDetected {
+    fn drop(&mut self) {
+        unsafe extern "C" {
+            fn better_option_result_panic_path_detected() -> !;
+        }
+        unsafe { better_option_result_panic_path_detected() }
+    }
+}
+
+#[inline(never)]
+fn exercise_boption(option: BOption<u32>, other: BOption<u32>) -> u32 {
+    let guard = PanicPathDetected;
+    let mut slot = option.into_xor(other).into_filter(|t| *t != 7);
+    let inserted = *slot.as_insert_or_else(|| 3);
+    let taken = slot.as_take().into_map(|t| t.wrapping_mul(2)).unwrap_or_default();
+    let result = BOption::Some(taken).into_bresult_or(inserted).unwrap_or_else(|e| e);
+    mem::forget(guard);
+    result.wrapping_add(inserted)
+}
+
+#[inline(never)]
+fn exercise_bresult(result: BResult<u32, u8>) -> u32 {
+    let guard = PanicPathDetected;
+    let mapped = result
+        .into_map_ok_flatten_lazy(|t| if t > 10 { BResult::Ok(t) } else { BResult::Err(0) })
+        .into_map_err(u32::from)
+        .into_boption()
+        .unwrap_or(1);
+    mem::forget(guard);
+    mapped
+}
+
+#[inline(never)]
+fn exercise_bstack(values: &[u8]) -> usize {
+    let guard = PanicPathDetected;
+    let mut stack: BStack<u8, 4> = BStack::new();
+    for value in values {
+        let _ = stack.as_push(*value);
+    }
+    let popped = stack.as_pop().into_map_or(0, usize::from);
+    let len = stack.as_slice().len();
+    mem::forget(guard);
+    popped.wrapping_add(len)
+}
+
+#[inline(never)]
+fn exercise_boption_insert(option: BOption<u32>, fallback: u32) -> u32 {
+    let guard = PanicPathDetected;
+    let mut slot = option;
+    let first = *slot.as_insert_or(fallback);
+    let mut empty = BOption::None;
+    let second = *empty.as_insert_or_default();
+    let third = *BOption::None.as_insert(fallback);
+    let mut lazy = BOption::None;
+    let fourth = lazy.as_insert_or_try_else(|| BResult::<u32, u8>::Err(1)).into_map_ok(|t| *t).unwrap_or(2);
+    let fifth = lazy.as_insert_or_try_else_boption(|| BOption::Some(fallback)).into_map(|t| *t).unwrap_or(0);
+    let sixth = *slot.as_entry().and_modify(|t| *t = t.wrapping_add(1)).or_insert(0);
+    let removed = match slot.as_entry() {
+        BOptionEntry::Occupied(occupied) => occupied.remove(),
+        BOptionEntry::Vacant(vacant) => *vacant.insert(0),
+    };
+    mem::forget(guard);
+    first.wrapping_add(second).wrapping_add(third).wrapping_add(fourth).wrapping_add(fifth).wrapping_add(sixth).wrapping_add(removed)
+}
+
+#[inline(never)]
+fn exercise_bresult_in_place(result: BResult<u32, u8>) -> u32 {
+    let guard = PanicPathDetected;
+    let mut slot = result;
+    slot.as_map_err_in_place(|e| *e = e.wrapping_add(1));
+    slot.as_recover_ref(|e| u32::from(*e));
+    slot.as_map_ok_in_place(|t| *t = t.wrapping_mul(3));
+    let previous = slot.as_replace_err(9).unwrap_or(0);
+    let recovered = slot.into_map_err(u32::from).unwrap_or_else(|e| e);
+    mem::forget(guard);
+    previous.wrapping_add(recovered)
+}
+
+#[inline(never)]
+fn exercise_ffi(option: BOption<u32>) -> u32 {
+    let guard = PanicPathDetected;
+    let round_trip = option.into_ffi_option().into_boption().unwrap_or(0);
+    mem::forget(guard);
+    round_trip
+}
+
+#[inline(never)]
+fn exercise_bstack_into_iter(values: &[u8]) -> u32 {
+    let guard = PanicPathDetected;
+    let mut stack: BStack<u8, 4> = BStack::new();
+    for value in values {
+        let _ = stack.as_push(*value);
+    }
+    let sum = stack.into_iter().fold(0_u32, |sum, value| sum.wrapping_add(u32::from(value)));
+    mem::forget(guard);
+    sum
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "needs --release")]
+fn no_panic_paths() {
+    assert_eq!(exercise_boption(black_box(BOption::Some(5)), black_box(BOption::None)), 15);
+    assert_eq!(exercise_bresult(black_box(BResult::Ok(11))), 11);
+    assert_eq!(exercise_bstack(black_box(&[1, 2, 3, 4, 5, 6])), 7);
+    assert_eq!(exercise_boption_insert(black_box(BOption::Some(5)), black_box(4)), 24);
+    assert_eq!(exercise_bresult_in_place(black_box(BResult::Err(2))), 18);
+    assert_eq!(exercise_ffi(black_box(BOption::Some(42))), 42);
+    assert_eq!(exercise_bstack_into_iter(black_box(&[1, 2, 3, 4, 5, 6])), 10);
+}
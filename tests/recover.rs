@@ -0,0 +1,19 @@
+use better_option_result::prelude::*;
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+fn as_recover_moves_the_error_into_the_closure() {
+    let mut slot = BResult::<String, String>::Err("lost".to_owned());
+    let recovered = slot.as_recover(|e| e + " and found");
+    recovered.push('!');
+    assert!(matches!(slot, BResult::Ok(ref t) if t == "lost and found!"));
+}
+
+#[test]
+fn as_recover_ref_borrows_the_error_and_keeps_ok() {
+    let mut slot = BResult::<u32, u8>::Err(4);
+    slot.as_recover_ref(|e| u32::from(*e) * 2);
+    assert!(matches!(slot, BResult::Ok(8)));
+    slot.as_recover_ref(|_| 0);
+    assert!(matches!(slot, BResult::Ok(8)));
+}
@@ -0,0 +1,73 @@
+use better_option_result::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct CountsDrops(Rc<Cell<usize>>);
+
+impl Drop for CountsDrops {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+fn filled(drops: &Rc<Cell<usize>>, count: usize) -> BStack<CountsDrops, 4> {
+    let mut stack = BStack::new();
+    for _ in 0..count {
+        let _ = stack.as_push(CountsDrops(Rc::clone(drops)));
+    }
+    stack
+}
+
+#[test]
+fn partially_consumed_into_iter_drops_the_rest_once() {
+    let drops = Rc::new(Cell::new(0));
+    let mut iter = filled(&drops, 3).into_iter();
+    drop(iter.next());
+    assert_eq!(drops.get(), 1);
+    drop(iter);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn as_clear_drops_every_item_once() {
+    let drops = Rc::new(Cell::new(0));
+    let mut stack = filled(&drops, 3);
+    stack.as_clear();
+    assert_eq!(drops.get(), 3);
+    assert!(stack.is_empty());
+    drop(stack);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn as_append_moves_what_fits_and_drops_the_overflow() {
+    let drops = Rc::new(Cell::new(0));
+    let mut stack = filled(&drops, 3);
+    stack.as_append(filled(&drops, 2));
+    assert_eq!(stack.len(), 4);
+    assert_eq!(drops.get(), 1);
+    drop(stack);
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn as_append_counts_the_other_stacks_overflow() {
+    let mut stack = BStack::<u8, 2>::new();
+    let _ = stack.as_push(1);
+    let mut other = BStack::<u8, 2>::new();
+    for item in 2..=4 {
+        let _ = other.as_push(item);
+    }
+    assert_eq!(other.overflowed(), 1);
+    stack.as_append(other);
+    assert_eq!(stack.as_slice(), &[1, 2]);
+    assert_eq!(stack.overflowed(), 2);
+}
+
+#[test]
+fn into_context_past_capacity_keeps_the_innermost_frames() {
+    let result = BResult::<(), &str>::Err("inner").into_berror::<1>().into_context("a").into_context("b");
+    let BResult::Err(error) = result else { panic!("expected Err") };
+    assert_eq!(error.to_string(), "a: inner");
+    assert_eq!(error.overflowed(), 1);
+}
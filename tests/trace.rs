@@ -0,0 +1,26 @@
+use better_option_result::prelude::*;
+
+#[cfg(not(feature = "trace"))]
+#[test]
+fn traced_is_just_the_error_without_the_feature() {
+    assert_eq!(size_of::<Traced<u32>>(), size_of::<u32>());
+    assert!(Traced::<u32>::new(7).as_trace().is_empty());
+}
+
+#[cfg(feature = "trace")]
+#[test]
+fn as_trace_records_every_traced_step() {
+    let created = line!() + 1;
+    let result = BResult::<u8, u8>::Err(1).into_traced::<4>();
+    let mapped = line!() + 1;
+    let result = result.into_traced_map_err(u16::from);
+    let flattened = line!() + 1;
+    let result = result.into_traced_map_ok_flatten_lazy(BResult::Ok);
+    let untouched = result.into_map_err(|traced| traced);
+    let retraced = line!() + 1;
+    let result = untouched.into_retraced().into_retraced();
+    let BResult::Err(traced) = result else { panic!("expected Err") };
+    let lines: Vec<u32> = traced.as_trace().iter().map(|location| location.line()).collect();
+    assert_eq!(lines, [created, mapped, flattened, retraced]);
+    assert_eq!(traced.overflowed(), 1);
+}
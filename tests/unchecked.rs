@@ -0,0 +1,30 @@
+#![cfg(debug_assertions)]
+
+use better_option_result::betteroption::FfiOption;
+use better_option_result::prelude::*;
+
+#[test]
+#[should_panic(expected = "BOption::unwrap_unchecked called on a None value")]
+fn unwrap_unchecked_on_none_panics_in_debug() {
+    unsafe { BOption::<u8>::None.unwrap_unchecked() };
+}
+
+#[test]
+#[should_panic(expected = "BOption::unwrap_none_unchecked called on a Some value")]
+fn unwrap_none_unchecked_on_some_panics_in_debug() {
+    unsafe { BOption::Some(1_u8).unwrap_none_unchecked() };
+}
+
+#[test]
+#[should_panic(expected = "BResult::unwrap_unchecked called on an Err value")]
+fn bresult_unwrap_unchecked_on_err_panics_in_debug() {
+    unsafe { BResult::<u8, u8>::Err(1).unwrap_unchecked() };
+}
+
+#[test]
+#[should_panic(expected = "FfiOption::into_boption found an invalid tag")]
+fn ffi_option_with_a_corrupt_tag_panics_in_debug() {
+    let mut ffi = FfiOption::<u32>::new_none();
+    unsafe { (&raw mut ffi).cast::<u8>().write(7) };
+    let _ = ffi.into_boption();
+}
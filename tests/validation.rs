@@ -0,0 +1,36 @@
+use better_option_result::bvalidate;
+use better_option_result::prelude::*;
+
+type Errors = BStack<&'static str, 4>;
+
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+fn nonzero(value: u32) -> BValidation<u32, Errors> {
+    if value == 0 { BValidation::from_error("zero") } else { BValidation::Valid(value) }
+}
+
+fn after(value: u32, start: u32) -> BValidation<u32, Errors> {
+    if value > start { BValidation::Valid(value) } else { BValidation::from_error("not after start") }
+}
+
+fn range(start: u32, end: u32) -> BValidation<Range, Errors> {
+    bvalidate!(Range {
+        start: nonzero(start),
+        end: after(end, start),
+    })
+}
+
+#[test]
+fn bvalidate_fields_can_reuse_caller_names() {
+    let valid = range(1, 5).into_boption_valid().into_map(|range| (range.start, range.end));
+    assert_eq!(valid.into_option(), Some((1, 5)));
+}
+
+#[test]
+fn bvalidate_accumulates_every_error() {
+    let errors = range(0, 0).into_boption_invalid().into_map(|errors| errors.as_slice().to_vec());
+    assert_eq!(errors.into_option(), Some(vec!["zero", "not after start"]));
+}